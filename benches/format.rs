@@ -0,0 +1,48 @@
+//! Benchmarks formatting dates of a custom calendar with leap rules.
+//!
+//! The leap rule expressions are compiled when the calendar is built, so formatting only
+//! evaluates them. Run with `cargo bench --bench format`.
+
+use bevy_ingame_clock::{Calendar, CustomCalendar, Epoch, Month};
+use chrono::NaiveDateTime;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+fn leap_calendar() -> CustomCalendar {
+    CustomCalendar::builder()
+        .month(Month::new("Frostmoon", 30, 1))
+        .month(Month::new("Sunreach", 31, 0))
+        .month(Month::leap_month("Deepmoon", 29))
+        .weekdays(vec!["Moonday".to_string(), "Fireday".to_string(), "Starday".to_string()])
+        .leap_years("# % 4 == 0 && (# % 100 != 0 || # % 400 == 0)")
+        .leap_months("# % 19 == 3 || # % 19 == 11")
+        .epoch(Epoch::new("Age of Magic", 1000))
+        .build()
+}
+
+fn format_datetime(c: &mut Criterion) {
+    let calendar = leap_calendar();
+    let start_datetime = NaiveDateTime::default();
+
+    // Every call lands on another day, and every few calls on another year
+    let mut day = 0.0;
+    c.bench_function("format_datetime", |b| {
+        b.iter(|| {
+            day = (day + 37.0) % 1_000_000.0;
+            calendar.format_datetime(black_box(day * SECONDS_PER_DAY), start_datetime, Some("%A, %d %B %Y %H:%M"))
+        })
+    });
+
+    // Dates up to 200000 years before the epoch
+    let mut year = 0.0;
+    c.bench_function("format_datetime_far_from_epoch", |b| {
+        b.iter(|| {
+            year = (year + 7919.0) % 200_000.0;
+            calendar.format_datetime(black_box(-year * 365.0 * SECONDS_PER_DAY), start_datetime, None)
+        })
+    });
+}
+
+criterion_group!(benches, format_datetime);
+criterion_main!(benches);
@@ -2,7 +2,8 @@
 //!
 //! This example shows how to use the CustomCalendar to create a game world
 //! with a custom calendar system different from the Gregorian calendar.
-//! The calendar configuration is loaded from a RON file.
+//! The calendar configuration is loaded as an asset from `assets/fantasy.calendar.ron`.
+//! Run with `--features bevy/file_watcher` to reload the calendar when the file changes.
 //!
 //! Controls:
 //! - Space: Pause/Resume
@@ -10,40 +11,77 @@
 //! - R: Reset clock
 
 use bevy::prelude::*;
-use bevy_ingame_clock::{ClockCommands, ClockInterval, ClockIntervalEvent, CustomCalendar, InGameClock, InGameClockPlugin};
-use std::fs;
+use bevy_ingame_clock::{
+    CalendarAsset, CalendarAssetPlugin, CalendarReloaded, ClockCommands, ClockInterval, ClockIntervalEvent, CustomCalendar,
+    InGameClock, InGameClockPlugin,
+};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(InGameClockPlugin)
+        .add_plugins((InGameClockPlugin, CalendarAssetPlugin))
         .add_systems(Startup, setup)
-        .add_systems(Update, (display_time, handle_input, handle_clock_events))
+        .add_systems(Update, (display_time, handle_input, handle_clock_events, report_calendar))
         .run();
 }
 
 #[derive(Component)]
 struct ClockText;
 
-#[derive(Resource)]
-struct CalendarResource(CustomCalendar);
-
-fn setup(mut commands: Commands) {
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     // Camera
     commands.spawn(Camera2d);
 
-    // Load the fantasy calendar from RON file
-    let calendar_config = fs::read_to_string("examples/fantasy_calendar.ron")
-        .expect("Failed to read fantasy_calendar.ron");
-    
-    let fantasy_calendar: CustomCalendar = ron::from_str(&calendar_config)
-        .expect("Failed to parse fantasy_calendar.ron");
-    
-    // Print calendar info to console
-    println!("\n=== Loaded Fantasy Calendar from RON ===");
-    println!("Configuration file: examples/fantasy_calendar.ron");
+    // Create a clock following the fantasy calendar asset, once it is loaded
+    // One in-game day passes every 60 real seconds
+    let clock = InGameClock::new()
+        .with_calendar_asset(asset_server.load("fantasy.calendar.ron"))
+        .with_day_duration(60.0);
+
+    commands.insert_resource(clock);
+
+    // UI Text
+    commands.spawn((
+        Text::default(),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            top: Val::Px(20.0),
+            ..default()
+        },
+        ClockText,
+    ));
+
+    // Register interval to receive events when a day passes
+    commands.register_clock_interval(ClockInterval::Day);
+    // Register interval to receive events when a week passes
+    commands.register_clock_interval(ClockInterval::Week);
+}
+
+fn report_calendar(
+    mut reloaded: MessageReader<CalendarReloaded>,
+    calendars: Res<Assets<CalendarAsset>>,
+    mut clock: ResMut<InGameClock>,
+) {
+    for message in reloaded.read() {
+        if let Some(error) = &message.error {
+            println!("\n⚠️ Keeping the previous calendar: {}", error);
+            continue;
+        }
+        if let Some(CalendarAsset(calendar)) = calendars.get(message.asset) {
+            print_calendar(calendar);
+            // The day length comes from the calendar, so apply the day duration again
+            clock.set_day_duration(60.0);
+        }
+    }
+}
+
+fn print_calendar(fantasy_calendar: &CustomCalendar) {
+    println!("\n=== Loaded Fantasy Calendar asset ===");
+    println!("Configuration file: assets/fantasy.calendar.ron");
 
     println!("\nCalendar structure:");
+    println!("  - {} seconds per minute", fantasy_calendar.seconds_per_minute);
     println!("  - {} minutes per hour", fantasy_calendar.minutes_per_hour);
     println!("  - {} hours per day", fantasy_calendar.hours_per_day);
     println!("  - {} days per week", fantasy_calendar.weekdays.len());
@@ -71,34 +109,6 @@ fn setup(mut commands: Commands) {
     println!("  +/-   - Speed Up/Down");
     println!("  R     - Reset clock");
     println!();
-
-    let calendar_clone = fantasy_calendar.clone();
-    commands.insert_resource(CalendarResource(calendar_clone));
-
-    // Create a clock with the fantasy calendar
-    // One in-game day passes every 60 real seconds
-    let clock = InGameClock::new()
-        .with_calendar(fantasy_calendar)
-        .with_day_duration(60.0);
-
-    commands.insert_resource(clock);
-
-    // UI Text
-    commands.spawn((
-        Text::default(),
-        Node {
-            position_type: PositionType::Absolute,
-            left: Val::Px(20.0),
-            top: Val::Px(20.0),
-            ..default()
-        },
-        ClockText,
-    ));
-
-    // Register interval to receive events when a day passes
-    commands.register_clock_interval(ClockInterval::Day);
-    // Register interval to receive events when a week passes
-    commands.register_clock_interval(ClockInterval::Week);
 }
 
 fn handle_clock_events(mut events: MessageReader<ClockIntervalEvent>) {
@@ -117,9 +127,12 @@ fn handle_clock_events(mut events: MessageReader<ClockIntervalEvent>) {
 
 fn display_time(
     clock: Res<InGameClock>,
-    calendar: Res<CalendarResource>,
+    calendars: Res<Assets<CalendarAsset>>,
     mut query: Query<&mut Text, With<ClockText>>,
 ) {
+    let Some(CalendarAsset(calendar)) = clock.calendar_asset().and_then(|handle| calendars.get(handle)) else {
+        return;
+    };
     if clock.is_changed() || query.iter().next().is_some() {
         for mut text in &mut query {
             // Display using default format
@@ -135,8 +148,8 @@ fn display_time(
             let (hour, minute, second) = clock.current_time();
             
             // Check if current year is a leap year using the calendar's method
-            let is_leap = calendar.0.is_leap_year(year);
-            let leap_years_expression = calendar.0.leap_years.clone();
+            let is_leap = calendar.is_leap_year(year);
+            let leap_years_expression = calendar.leap_years.clone();
             
             let status = if clock.paused { "PAUSED" } else { "RUNNING" };
             
@@ -5,40 +5,55 @@
 //! - How to handle different interval events
 //! - Using built-in intervals (Second, Minute, Hour, Day, Week)
 //! - Using custom intervals
+//! - Coalescing Second events so high speeds do not flood the log
 //! - Toggle events on/off with number keys
 
 use bevy::prelude::*;
-use bevy_ingame_clock::{ClockCommands, ClockInterval, ClockIntervalEvent, InGameClock, InGameClockPlugin};
+use bevy_ingame_clock::{
+    CatchUpPolicy, ClockCommands, ClockInterval, ClockIntervalEvent, InGameClock, InGameClockPlugin, IntervalHandle,
+    IntervalSettings,
+};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(InGameClockPlugin)
-        .init_resource::<ActiveIntervals>()
         .add_systems(Startup, setup)
         .add_systems(Update, (handle_interval_events, display_info, handle_input))
         .run();
 }
 
+/// Handles of the currently registered intervals, `None` when disabled
 #[derive(Resource)]
 struct ActiveIntervals {
-    second: bool,
-    minute: bool,
-    hour: bool,
-    day: bool,
-    week: bool,
-    custom: bool,
+    second: Option<IntervalHandle>,
+    minute: Option<IntervalHandle>,
+    hour: Option<IntervalHandle>,
+    day: Option<IntervalHandle>,
+    week: Option<IntervalHandle>,
+    custom: Option<IntervalHandle>,
 }
 
-impl Default for ActiveIntervals {
-    fn default() -> Self {
-        Self {
-            second: true,
-            minute: true,
-            hour: true,
-            day: true,
-            week: false,
-            custom: true,
+/// Settings for Second events: at high speeds, one event per frame reports the seconds it covers
+fn second_settings() -> IntervalSettings {
+    IntervalSettings::default().with_catch_up(CatchUpPolicy::Coalesce)
+}
+
+/// Register the interval if it is disabled, or unregister it if it is enabled
+fn toggle_interval(
+    commands: &mut Commands,
+    slot: &mut Option<IntervalHandle>,
+    interval: ClockInterval,
+    settings: IntervalSettings,
+) -> bool {
+    match slot.take() {
+        Some(handle) => {
+            commands.unregister_clock_interval(handle);
+            false
+        }
+        None => {
+            *slot = Some(commands.register_clock_interval_with(interval, settings));
+            true
         }
     }
 }
@@ -53,12 +68,16 @@ fn setup(mut commands: Commands) {
             .with_day_duration(600.0) // 1 day per 600 real seconds
     );
 
-    // Register initial intervals based on defaults
-    commands.register_clock_interval(ClockInterval::Second);
-    commands.register_clock_interval(ClockInterval::Minute);
-    commands.register_clock_interval(ClockInterval::Hour);
-    commands.register_clock_interval(ClockInterval::Day);
-    commands.register_clock_interval(ClockInterval::Custom(30));
+    // Register initial intervals and keep their handles to unregister them later
+    let active = ActiveIntervals {
+        second: Some(commands.register_clock_interval_with(ClockInterval::Second, second_settings())),
+        minute: Some(commands.register_clock_interval(ClockInterval::Minute)),
+        hour: Some(commands.register_clock_interval(ClockInterval::Hour)),
+        day: Some(commands.register_clock_interval(ClockInterval::Day)),
+        week: None,
+        custom: Some(commands.register_clock_interval(ClockInterval::Custom(30))),
+    };
+    commands.insert_resource(active);
 
     // Spawn UI text
     commands.spawn((
@@ -89,30 +108,20 @@ struct EventLog {
 fn handle_interval_events(
     mut events: MessageReader<ClockIntervalEvent>,
     mut log: Local<EventLog>,
-    active: Res<ActiveIntervals>,
 ) {
+    // Disabled intervals are unregistered, so every event here is wanted
     for event in events.read() {
-        // Only log events that are currently enabled
-        let should_log = match event.interval {
-            ClockInterval::Second => active.second,
-            ClockInterval::Minute => active.minute,
-            ClockInterval::Hour => active.hour,
-            ClockInterval::Day => active.day,
-            ClockInterval::Week => active.week,
-            ClockInterval::Custom(_) => active.custom,
-        };
-
-        if !should_log {
-            continue;
-        }
-
         let message = match event.interval {
-            ClockInterval::Second => format!("⏱️  Second passed (count: {})", event.count),
+            ClockInterval::Second => format!("⏱️  Second passed (count: {}, missed: {})", event.count, event.missed),
             ClockInterval::Minute => format!("⏰ Minute passed (count: {})", event.count),
             ClockInterval::Hour => format!("🕐 Hour passed (count: {})", event.count),
             ClockInterval::Day => format!("📅 Day passed (count: {})", event.count),
             ClockInterval::Week => format!("📆 Week passed (count: {})", event.count),
             ClockInterval::Custom(seconds) => format!("⚡ Custom interval ({} seconds) passed (count: {})", seconds, event.count),
+            ClockInterval::Every(amount, unit) => format!("⚡ Every {} {:?} passed (count: {})", amount, unit, event.count),
+            ClockInterval::Month => format!("🗓️  Month passed (count: {})", event.count),
+            ClockInterval::Year => format!("🎆 Year passed (count: {})", event.count),
+            ClockInterval::Season => format!("🍂 Season passed (count: {})", event.count),
         };
         
         println!("{}", message);
@@ -141,12 +150,12 @@ fn display_info(
         
         let interval_status = format!(
             "1: Second [{}]\n2: Minute [{}]\n3: Hour [{}]\n4: Day [{}]\n5: Week [{}]\n6: Custom(30s) [{}]",
-            if active.second { "ON" } else { "OFF" },
-            if active.minute { "ON" } else { "OFF" },
-            if active.hour { "ON" } else { "OFF" },
-            if active.day { "ON" } else { "OFF" },
-            if active.week { "ON" } else { "OFF" },
-            if active.custom { "ON" } else { "OFF" },
+            if active.second.is_some() { "ON" } else { "OFF" },
+            if active.minute.is_some() { "ON" } else { "OFF" },
+            if active.hour.is_some() { "ON" } else { "OFF" },
+            if active.day.is_some() { "ON" } else { "OFF" },
+            if active.week.is_some() { "ON" } else { "OFF" },
+            if active.custom.is_some() { "ON" } else { "OFF" },
         );
         
         **text = format!(
@@ -184,56 +193,38 @@ fn handle_input(
 
     // Toggle Second events with 1
     if keyboard.just_pressed(KeyCode::Digit1) {
-        active.second = !active.second;
-        if active.second {
-            commands.register_clock_interval(ClockInterval::Second);
-        }
-        println!("Second events: {}", if active.second { "enabled" } else { "disabled" });
+        let enabled = toggle_interval(&mut commands, &mut active.second, ClockInterval::Second, second_settings());
+        println!("Second events: {}", if enabled { "enabled" } else { "disabled" });
     }
 
     // Toggle Minute events with 2
     if keyboard.just_pressed(KeyCode::Digit2) {
-        active.minute = !active.minute;
-        if active.minute {
-            commands.register_clock_interval(ClockInterval::Minute);
-        }
-        println!("Minute events: {}", if active.minute { "enabled" } else { "disabled" });
+        let enabled = toggle_interval(&mut commands, &mut active.minute, ClockInterval::Minute, IntervalSettings::default());
+        println!("Minute events: {}", if enabled { "enabled" } else { "disabled" });
     }
 
     // Toggle Hour events with 3
     if keyboard.just_pressed(KeyCode::Digit3) {
-        active.hour = !active.hour;
-        if active.hour {
-            commands.register_clock_interval(ClockInterval::Hour);
-        }
-        println!("Hour events: {}", if active.hour { "enabled" } else { "disabled" });
+        let enabled = toggle_interval(&mut commands, &mut active.hour, ClockInterval::Hour, IntervalSettings::default());
+        println!("Hour events: {}", if enabled { "enabled" } else { "disabled" });
     }
 
     // Toggle Day events with 4
     if keyboard.just_pressed(KeyCode::Digit4) {
-        active.day = !active.day;
-        if active.day {
-            commands.register_clock_interval(ClockInterval::Day);
-        }
-        println!("Day events: {}", if active.day { "enabled" } else { "disabled" });
+        let enabled = toggle_interval(&mut commands, &mut active.day, ClockInterval::Day, IntervalSettings::default());
+        println!("Day events: {}", if enabled { "enabled" } else { "disabled" });
     }
 
     // Toggle Week events with 5
     if keyboard.just_pressed(KeyCode::Digit5) {
-        active.week = !active.week;
-        if active.week {
-            commands.register_clock_interval(ClockInterval::Week);
-        }
-        println!("Week events: {}", if active.week { "enabled" } else { "disabled" });
+        let enabled = toggle_interval(&mut commands, &mut active.week, ClockInterval::Week, IntervalSettings::default());
+        println!("Week events: {}", if enabled { "enabled" } else { "disabled" });
     }
 
     // Toggle Custom events with 6
     if keyboard.just_pressed(KeyCode::Digit6) {
-        active.custom = !active.custom;
-        if active.custom {
-            commands.register_clock_interval(ClockInterval::Custom(30));
-        }
-        println!("Custom(30s) events: {}", if active.custom { "enabled" } else { "disabled" });
+        let enabled = toggle_interval(&mut commands, &mut active.custom, ClockInterval::Custom(30), IntervalSettings::default());
+        println!("Custom(30s) events: {}", if enabled { "enabled" } else { "disabled" });
     }
 
     // Reset with R
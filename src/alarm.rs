@@ -0,0 +1,295 @@
+//! One-shot alarms that fire at a specific in-game date and time.
+
+use crate::{DateError, InGameClock};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Source of unique ids for scheduled alarms
+pub(crate) static NEXT_ALARM_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Identifier of a scheduled alarm, used to cancel it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AlarmId(pub(crate) u64);
+
+impl AlarmId {
+    pub(crate) fn new() -> Self {
+        Self(NEXT_ALARM_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// The in-game moment at which an alarm fires
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AlarmTime {
+    /// An absolute point in time, in elapsed in-game seconds since the clock started
+    Elapsed(f64),
+    /// A date and time in the clock's calendar
+    DateTime {
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    },
+}
+
+/// A one-shot alarm that fires an [`AlarmFired`] message once the in-game time reaches it
+///
+/// Alarms fire as soon as the clock is at or past their time, so they are not missed
+/// when the clock skips over the exact moment in a single frame. An alarm scheduled
+/// for a time that has already passed fires on the next update. The date and time of an
+/// alarm are resolved in the clock's calendar when it is scheduled.
+///
+/// # Examples
+/// ```
+/// # use bevy_ingame_clock::ClockAlarm;
+/// // Fire at dawn on the 3rd day of the first month of year 1024
+/// let alarm = ClockAlarm::at_datetime(1024, 1, 3, 6, 0, 0).with_label("caravan arrives");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClockAlarm {
+    /// When the alarm fires
+    pub time: AlarmTime,
+    /// User-defined payload passed along in [`AlarmFired`]
+    pub label: Option<String>,
+}
+
+impl ClockAlarm {
+    /// Creates an alarm firing at an absolute elapsed in-game time
+    pub fn at_elapsed(elapsed_seconds: f64) -> Self {
+        Self {
+            time: AlarmTime::Elapsed(elapsed_seconds),
+            label: None,
+        }
+    }
+
+    /// Creates an alarm firing at a date and time in the clock's calendar
+    pub fn at_datetime(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Self {
+        Self {
+            time: AlarmTime::DateTime {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            },
+            label: None,
+        }
+    }
+
+    /// Sets the label passed along when the alarm fires
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Get the elapsed in-game seconds of the clock at which this alarm fires
+    ///
+    /// Returns a [`DateError`] if the date or time of the alarm does not exist in the
+    /// clock's calendar.
+    pub fn elapsed_seconds(&self, clock: &InGameClock) -> Result<f64, DateError> {
+        match self.time {
+            AlarmTime::Elapsed(elapsed_seconds) => Ok(elapsed_seconds),
+            AlarmTime::DateTime {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            } => clock.calendar().to_elapsed((year, month, day), (hour, minute, second), clock.start_datetime),
+        }
+    }
+
+    /// Check whether the clock has reached the time of this alarm
+    ///
+    /// Alarms at a date or time that does not exist in the clock's calendar are never due.
+    pub fn is_due(&self, clock: &InGameClock) -> bool {
+        self.elapsed_seconds(clock).is_ok_and(|elapsed_seconds| clock.elapsed_seconds >= elapsed_seconds)
+    }
+}
+
+/// Message sent when a scheduled [`ClockAlarm`] fires
+#[derive(Message, Debug, Clone)]
+pub struct AlarmFired {
+    /// The id returned when the alarm was scheduled
+    pub id: AlarmId,
+    /// The label of the alarm
+    pub label: Option<String>,
+    /// The elapsed in-game seconds at which the alarm was detected
+    pub elapsed_seconds: f64,
+}
+
+/// Resource holding all alarms that have not fired yet
+#[derive(Resource, Default)]
+pub(crate) struct ClockAlarms {
+    pub(crate) alarms: Vec<(AlarmId, ClockAlarm)>,
+}
+
+impl InGameClock {
+    /// Schedule a one-shot alarm
+    ///
+    /// The alarm is stored at the elapsed time of its date and time in the clock's calendar.
+    /// Returns a [`DateError`], and schedules nothing, if they do not exist in the calendar.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ingame_clock::{ClockAlarm, InGameClock, InGameClockPlugin};
+    /// let mut app = App::new();
+    /// app.add_plugins(InGameClockPlugin);
+    ///
+    /// let alarm = InGameClock::schedule_alarm(app.world_mut(), ClockAlarm::at_elapsed(3600.0)).unwrap();
+    /// InGameClock::cancel_alarm(app.world_mut(), alarm);
+    ///
+    /// // February 30th does not exist
+    /// assert!(InGameClock::schedule_alarm(app.world_mut(), ClockAlarm::at_datetime(2024, 2, 30, 0, 0, 0)).is_err());
+    /// ```
+    pub fn schedule_alarm(world: &mut World, alarm: ClockAlarm) -> Result<AlarmId, DateError> {
+        let id = AlarmId::new();
+        Self::schedule_alarm_with_id(world, id, alarm)?;
+        Ok(id)
+    }
+
+    pub(crate) fn schedule_alarm_with_id(world: &mut World, id: AlarmId, alarm: ClockAlarm) -> Result<(), DateError> {
+        let elapsed_seconds = alarm.elapsed_seconds(world.resource::<InGameClock>())?;
+        let alarm = ClockAlarm {
+            time: AlarmTime::Elapsed(elapsed_seconds),
+            ..alarm
+        };
+        world.resource_mut::<ClockAlarms>().alarms.push((id, alarm));
+        Ok(())
+    }
+
+    /// Cancel a scheduled alarm. Cancelling an alarm that already fired does nothing.
+    pub fn cancel_alarm(world: &mut World, id: AlarmId) {
+        world.resource_mut::<ClockAlarms>().alarms.retain(|(alarm_id, _)| *alarm_id != id);
+    }
+}
+
+/// System that fires and removes all alarms whose time has been reached
+pub(crate) fn check_alarms(
+    clock: Res<InGameClock>,
+    mut alarms: ResMut<ClockAlarms>,
+    mut events: MessageWriter<AlarmFired>,
+) {
+    if clock.paused {
+        return;
+    }
+
+    alarms.alarms.retain(|(id, alarm)| {
+        if !alarm.is_due(&clock) {
+            return true;
+        }
+        events.write(AlarmFired {
+            id: *id,
+            label: alarm.label.clone(),
+            elapsed_seconds: clock.elapsed_seconds,
+        });
+        false
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ClockCommands, CustomCalendar, Epoch, InGameClockPlugin, Month};
+
+    fn fired_alarms(app: &mut App) -> Vec<AlarmFired> {
+        app.update();
+        app.world_mut().resource_mut::<Messages<AlarmFired>>().drain().collect()
+    }
+
+    #[test]
+    fn test_alarm_fires_once_after_time_jump() {
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin)
+            .init_resource::<Time>()
+            .insert_resource(InGameClock::with_start_datetime(2024, 1, 1, 0, 0, 0));
+        let id = InGameClock::schedule_alarm(
+            app.world_mut(),
+            ClockAlarm::at_datetime(2024, 1, 3, 6, 0, 0).with_label("dawn"),
+        )
+        .unwrap();
+
+        assert!(fired_alarms(&mut app).is_empty());
+
+        // Skip far past the alarm in a single frame
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = 10.0 * 86400.0;
+        let fired = fired_alarms(&mut app);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].id, id);
+        assert_eq!(fired[0].label.as_deref(), Some("dawn"));
+
+        assert!(fired_alarms(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_alarm_fires_in_the_frame_the_clock_reaches_it() {
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin).init_resource::<Time>();
+        InGameClock::schedule_alarm(app.world_mut(), ClockAlarm::at_elapsed(0.5)).unwrap();
+
+        // The clock advances and the alarm is checked against the new time in the same update
+        app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_secs(1));
+        let fired = fired_alarms(&mut app);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].elapsed_seconds, 1.0);
+    }
+
+    #[test]
+    fn test_cancel_alarm() {
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin).init_resource::<Time>();
+
+        let mut queue = bevy::ecs::world::CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, app.world());
+        let cancelled = commands.schedule_clock_alarm(ClockAlarm::at_elapsed(60.0));
+        let kept = commands.schedule_clock_alarm(ClockAlarm::at_elapsed(120.0));
+        commands.cancel_clock_alarm(cancelled);
+        queue.apply(app.world_mut());
+
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = 200.0;
+        let fired = fired_alarms(&mut app);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].id, kept);
+    }
+
+    #[test]
+    fn test_alarm_in_custom_calendar() {
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(20)
+            .month(Month::new("Frostmoon", 20, 0))
+            .month(Month::new("Thawmoon", 21, 0))
+            .weekday("Moonday")
+            .epoch(Epoch::new("Age of Magic", 1024))
+            .build();
+        let mut clock = InGameClock::new().with_calendar(calendar);
+
+        let alarm = ClockAlarm::at_datetime(1024, 2, 3, 6, 0, 0);
+        let seconds_per_day = 72000.0;
+        clock.elapsed_seconds = 22.0 * seconds_per_day + 6.0 * 3600.0 - 1.0;
+        assert!(!alarm.is_due(&clock));
+        clock.elapsed_seconds += 1.0;
+        assert!(alarm.is_due(&clock));
+
+        // Dates and times missing from the calendar are rejected when scheduling
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin).init_resource::<Time>().insert_resource(clock);
+        for (alarm, error) in [
+            (ClockAlarm::at_datetime(1024, 2, 22, 0, 0, 0), DateError::InvalidDate { year: 1024, month: 2, day: 22 }),
+            (ClockAlarm::at_datetime(1024, 3, 1, 0, 0, 0), DateError::InvalidDate { year: 1024, month: 3, day: 1 }),
+            (ClockAlarm::at_datetime(1024, 1, 1, 23, 0, 0), DateError::InvalidTime { hour: 23, minute: 0, second: 0 }),
+        ] {
+            assert!(!alarm.is_due(app.world().resource::<InGameClock>()));
+            assert_eq!(InGameClock::schedule_alarm(app.world_mut(), alarm), Err(error));
+        }
+        assert!(app.world().resource::<ClockAlarms>().alarms.is_empty());
+
+        // Valid alarms are stored at their elapsed time
+        InGameClock::schedule_alarm(app.world_mut(), ClockAlarm::at_datetime(1024, 1, 2, 0, 0, 0)).unwrap();
+        assert_eq!(app.world().resource::<ClockAlarms>().alarms[0].1.time, AlarmTime::Elapsed(seconds_per_day));
+    }
+}
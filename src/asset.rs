@@ -0,0 +1,320 @@
+//! Custom calendars loaded as Bevy assets, with hot reloading.
+
+use crate::{Calendar, CalendarError, CustomCalendar, InGameClock};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoadError, AssetLoadFailedEvent, AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+
+/// A [`CustomCalendar`] loaded from a `.calendar.ron` or `.calendar.json` file
+///
+/// The file holds the calendar fields directly, as in the RON examples of [`CustomCalendar`].
+/// Give the handle to a clock with [`InGameClock::with_calendar_asset`] to use the calendar
+/// once it is loaded, and every time the file changes.
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CalendarAsset(pub CustomCalendar);
+
+/// Error returned when a calendar file cannot be read or parsed
+#[derive(Debug)]
+pub enum CalendarLoaderError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The `.calendar.ron` file is not a valid calendar
+    Ron(ron::error::SpannedError),
+    /// The `.calendar.json` file is not a valid calendar
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for CalendarLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarLoaderError::Io(error) => write!(f, "could not read calendar file: {}", error),
+            CalendarLoaderError::Ron(error) => write!(f, "could not parse RON calendar: {}", error),
+            CalendarLoaderError::Json(error) => write!(f, "could not parse JSON calendar: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for CalendarLoaderError {}
+
+impl From<std::io::Error> for CalendarLoaderError {
+    fn from(error: std::io::Error) -> Self {
+        CalendarLoaderError::Io(error)
+    }
+}
+
+/// Loader for [`CalendarAsset`]s, registered by [`CalendarAssetPlugin`]
+///
+/// Files ending in `.calendar.json` are parsed as JSON, every other file as RON. The
+/// calendar is not validated here, so that an invalid file on hot reload is reported by
+/// [`CalendarReloaded`] instead of failing the load.
+#[derive(Debug, Default)]
+pub struct CalendarAssetLoader;
+
+impl CalendarAssetLoader {
+    fn parse(bytes: &[u8], json: bool) -> Result<CalendarAsset, CalendarLoaderError> {
+        if json {
+            serde_json::from_slice(bytes).map_err(CalendarLoaderError::Json)
+        } else {
+            ron::de::from_bytes(bytes).map_err(CalendarLoaderError::Ron)
+        }
+    }
+}
+
+impl AssetLoader for CalendarAssetLoader {
+    type Asset = CalendarAsset;
+    type Settings = ();
+    type Error = CalendarLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<CalendarAsset, CalendarLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let json = load_context.path().extension().is_some_and(|extension| extension == "json");
+        Self::parse(&bytes, json)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["calendar.ron", "calendar.json"]
+    }
+}
+
+/// Why the calendar asset of the clock was not applied
+#[derive(Debug, Clone)]
+pub enum CalendarReloadError {
+    /// The file could not be loaded or parsed
+    Load(AssetLoadError),
+    /// The file describes an invalid calendar
+    Invalid(CalendarError),
+}
+
+impl fmt::Display for CalendarReloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarReloadError::Load(error) => write!(f, "{}", error),
+            CalendarReloadError::Invalid(error) => write!(f, "invalid calendar: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for CalendarReloadError {}
+
+/// Message sent when the calendar asset of the clock was loaded or changed
+///
+/// On success the clock uses the new calendar from this frame on. When the file changed, the
+/// clock keeps its elapsed seconds and shows the same date and time as before; if they do not
+/// exist in the new calendar, it keeps the offset from the calendar origin instead, so the date
+/// is recomputed. On error the clock keeps the calendar it had.
+#[derive(Message, Debug, Clone)]
+pub struct CalendarReloaded {
+    /// The calendar asset of the clock
+    pub asset: AssetId<CalendarAsset>,
+    /// Why the calendar was not applied, or `None` if the clock now uses it
+    pub error: Option<CalendarReloadError>,
+}
+
+/// Plugin loading [`CalendarAsset`]s and applying them to the [`InGameClock`]
+///
+/// Add it after Bevy's `AssetPlugin` (part of `DefaultPlugins`) and [`crate::InGameClockPlugin`].
+///
+/// # Examples
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ingame_clock::{CalendarAssetPlugin, InGameClock, InGameClockPlugin};
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     let calendar = asset_server.load("fantasy.calendar.ron");
+///     commands.insert_resource(InGameClock::new().with_calendar_asset(calendar));
+/// }
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, InGameClockPlugin, CalendarAssetPlugin))
+///     .add_systems(Startup, setup)
+///     .run();
+/// ```
+pub struct CalendarAssetPlugin;
+
+impl Plugin for CalendarAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<CalendarAsset>()
+            .register_asset_loader(CalendarAssetLoader)
+            .add_message::<CalendarReloaded>()
+            .add_systems(Update, apply_calendar_asset.before(crate::update_clock));
+    }
+}
+
+impl InGameClock {
+    /// Use the calendar of a [`CalendarAsset`] once it is loaded, and again whenever it changes
+    ///
+    /// Until the asset is loaded, the clock keeps its current calendar.
+    pub fn with_calendar_asset(mut self, handle: Handle<CalendarAsset>) -> Self {
+        self.set_calendar_asset(handle);
+        self
+    }
+
+    /// Switch to the calendar of a [`CalendarAsset`], see [`InGameClock::with_calendar_asset`]
+    pub fn set_calendar_asset(&mut self, handle: Handle<CalendarAsset>) {
+        self.calendar_asset = Some(handle);
+    }
+
+    /// Get the calendar asset followed by this clock, if any
+    pub fn calendar_asset(&self) -> Option<&Handle<CalendarAsset>> {
+        self.calendar_asset.as_ref()
+    }
+}
+
+/// System that applies the calendar asset of the clock when it is loaded or changes
+fn apply_calendar_asset(
+    mut clock: ResMut<InGameClock>,
+    calendars: Res<Assets<CalendarAsset>>,
+    mut asset_events: MessageReader<AssetEvent<CalendarAsset>>,
+    mut failures: MessageReader<AssetLoadFailedEvent<CalendarAsset>>,
+    mut applied: Local<Option<AssetId<CalendarAsset>>>,
+    mut reloaded: MessageWriter<CalendarReloaded>,
+) {
+    let Some(id) = clock.calendar_asset.as_ref().map(Handle::id) else {
+        asset_events.clear();
+        failures.clear();
+        return;
+    };
+
+    for failure in failures.read().filter(|failure| failure.id == id) {
+        reloaded.write(CalendarReloaded {
+            asset: id,
+            error: Some(CalendarReloadError::Load(failure.error.clone())),
+        });
+    }
+
+    // Apply the asset when it changed, or when the clock switched to another asset
+    let changed = asset_events
+        .read()
+        .filter(|event| event.is_added(id) || event.is_modified(id))
+        .count()
+        > 0;
+    if !changed && *applied == Some(id) {
+        return;
+    }
+    let Some(CalendarAsset(calendar)) = calendars.get(id) else {
+        return;
+    };
+    let reload = *applied == Some(id);
+    *applied = Some(id);
+
+    let error = match calendar.validate() {
+        Ok(()) => {
+            // A changed file keeps the date and time shown by the clock. The first calendar of an
+            // asset, or a date missing from the changed calendar, keeps the offset from its origin
+            let start_offset = reload
+                .then(|| same_date_offset(&clock, calendar))
+                .flatten()
+                .unwrap_or(clock.start_offset());
+            clock.set_calendar(Arc::new(calendar.clone()), start_offset);
+            None
+        }
+        Err(error) => Some(CalendarReloadError::Invalid(error)),
+    };
+    reloaded.write(CalendarReloaded { asset: id, error });
+}
+
+/// Get the start offset at which `calendar` shows the current date and time of the clock,
+/// or `None` if that date or time does not exist in `calendar`
+fn same_date_offset(clock: &InGameClock, calendar: &CustomCalendar) -> Option<f64> {
+    let instant = clock.elapsed_seconds + clock.start_offset();
+    let elapsed = calendar
+        .to_elapsed(clock.current_date(), clock.current_time(), clock.start_datetime)
+        .ok()?;
+    Some(elapsed + (instant - instant.floor()) - clock.elapsed_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InGameClockPlugin, Month};
+
+    #[test]
+    fn test_parse_calendar_files() {
+        let ron = br##"(
+            minutes_per_hour: 60,
+            hours_per_day: 20,
+            months: [(name: "Frostmoon", days: 20, leap_days: 3)],
+            weekdays: ["Moonday", "Fireday"],
+            leap_years: "# % 2 == 0",
+            epoch: (name: "Age of Magic", start_year: 1000),
+        )"##;
+        let calendar = CalendarAssetLoader::parse(ron, false).unwrap().0;
+        assert_eq!(calendar.seconds_per_day(), 72000);
+        assert_eq!(calendar.days_in_year(1000), 23);
+
+        let json = br#"{
+            "minutes_per_hour": 60,
+            "hours_per_day": 20,
+            "months": [{"name": "Frostmoon", "days": 20, "leap_days": 3}],
+            "weekdays": ["Moonday", "Fireday"],
+            "leap_years": "false",
+            "epoch": {"name": "Age of Magic", "start_year": 1000}
+        }"#;
+        let calendar = CalendarAssetLoader::parse(json, true).unwrap().0;
+        assert_eq!(calendar.days_in_year(1000), 20);
+
+        assert!(matches!(CalendarAssetLoader::parse(json, false), Err(CalendarLoaderError::Ron(_))));
+    }
+
+    #[test]
+    fn test_calendar_hot_reload() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default(), InGameClockPlugin, CalendarAssetPlugin))
+            .init_resource::<Time>();
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(20)
+            .month(Month::new("Frostmoon", 20, 0))
+            .weekday("Moonday")
+            .build();
+        let handle = app.world_mut().resource_mut::<Assets<CalendarAsset>>().add(CalendarAsset(calendar));
+        app.world_mut().resource_mut::<InGameClock>().set_calendar_asset(handle.clone());
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = 72000.0 * 3.0;
+        app.update();
+
+        let reloaded = |app: &mut App| app.world_mut().resource_mut::<Messages<CalendarReloaded>>().drain().collect::<Vec<_>>();
+        assert!(reloaded(&mut app)[0].error.is_none());
+        assert_eq!(app.world().resource::<InGameClock>().current_date(), (1, 1, 4));
+
+        // Invalid changes are reported, and the clock keeps its calendar
+        app.world_mut().resource_mut::<Assets<CalendarAsset>>().get_mut(&handle).unwrap().0.hours_per_day = 0;
+        app.update();
+        app.update();
+        let messages = reloaded(&mut app);
+        assert!(matches!(
+            messages[0].error,
+            Some(CalendarReloadError::Invalid(CalendarError::ZeroTimeUnit { field: "hours_per_day" }))
+        ));
+        assert_eq!(app.world().resource::<InGameClock>().calendar().seconds_per_day(), 72000);
+
+        // Valid changes swap the calendar at the same date and time
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds += 5.0 * 3600.0 + 0.5;
+        app.world_mut().resource_mut::<Assets<CalendarAsset>>().get_mut(&handle).unwrap().0.hours_per_day = 10;
+        app.update();
+        app.update();
+        assert!(reloaded(&mut app)[0].error.is_none());
+        let clock = app.world().resource::<InGameClock>();
+        assert_eq!(clock.calendar().seconds_per_day(), 36000);
+        assert_eq!(clock.elapsed_seconds, 72000.0 * 3.0 + 5.0 * 3600.0 + 0.5);
+        assert_eq!(clock.current_date(), (1, 1, 4));
+        assert_eq!(clock.current_time(), (5, 0, 0));
+        assert_eq!(clock.start_offset(), 36000.0 * 3.0 - 72000.0 * 3.0);
+
+        // A time missing from the changed calendar keeps the offset, and the date is recomputed
+        app.world_mut().resource_mut::<Assets<CalendarAsset>>().get_mut(&handle).unwrap().0.hours_per_day = 4;
+        app.update();
+        app.update();
+        assert!(reloaded(&mut app)[0].error.is_none());
+        let clock = app.world().resource::<InGameClock>();
+        assert_eq!(clock.start_offset(), 36000.0 * 3.0 - 72000.0 * 3.0);
+        assert_eq!(clock.current_date(), (1, 1, 9));
+    }
+}
@@ -6,6 +6,8 @@
 use chrono::{Datelike, Duration, NaiveDateTime, Timelike};
 use evalexpr::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 /// Trait for implementing custom calendar systems
 ///
@@ -36,6 +38,14 @@ pub trait Calendar: Send + Sync {
         86400
     }
     
+    /// Get seconds per minute for this calendar system
+    ///
+    /// Default: 60 (standard Gregorian minute)
+    /// Custom calendars should override this to return their configured value.
+    fn seconds_per_minute(&self) -> u32 {
+        60
+    }
+    
     /// Get seconds per hour for this calendar system
     ///
     /// Default: 3600 (60 minutes × 60 seconds - standard Gregorian hour)
@@ -53,6 +63,393 @@ pub trait Calendar: Send + Sync {
     fn seconds_per_week(&self) -> u32 {
         self.seconds_per_day() * 7
     }
+    
+    /// Get the elapsed seconds at which the day containing `elapsed_seconds` started
+    ///
+    /// Default: derived from [`Calendar::get_time`] and the length of hours and minutes.
+    fn day_start(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> f64 {
+        let (hour, minute, second) = self.get_time(elapsed_seconds, start_datetime);
+        let seconds_today = hour * self.seconds_per_hour() + minute * self.seconds_per_minute() + second;
+        elapsed_seconds.floor() - seconds_today as f64
+    }
+    
+    /// Get the elapsed seconds at which the week containing `elapsed_seconds` started
+    ///
+    /// Default: weeks start on Monday at midnight, as in ISO 8601.
+    fn week_start(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> f64 {
+        let dt = offset_datetime(start_datetime, elapsed_seconds);
+        let days_since_monday = dt.weekday().num_days_from_monday() as f64;
+        self.day_start(elapsed_seconds, start_datetime) - days_since_monday * self.seconds_per_day() as f64
+    }
+    
+    /// Get the elapsed seconds at which the year containing `elapsed_seconds` started
+    ///
+    /// Default: the start of the first day of the first month of the year, from [`Calendar::to_elapsed`].
+    fn year_start(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> f64 {
+        let (year, _, _) = self.get_date(elapsed_seconds, start_datetime);
+        self.to_elapsed((year, 1, 1), (0, 0, 0), start_datetime)
+            .unwrap_or_else(|_| self.day_start(elapsed_seconds, start_datetime))
+    }
+    
+    /// Get the names of the months of a year, in order
+    ///
+    /// Default: the English Gregorian month names.
+    fn month_names(&self) -> Vec<String> {
+        [
+            "January", "February", "March", "April", "May", "June",
+            "July", "August", "September", "October", "November", "December",
+        ]
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+    }
+    
+    /// Get the number of days in the longest month of any year
+    ///
+    /// Default: 31, the longest Gregorian month.
+    fn max_days_in_month(&self) -> u32 {
+        31
+    }
+    
+    /// Get the names of the days of the week, starting with the day returned by [`Calendar::week_start`]
+    ///
+    /// Default: the English Gregorian weekday names, starting on Monday.
+    fn weekday_names(&self) -> Vec<String> {
+        ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    }
+    
+    /// Get the number of days in a week
+    ///
+    /// Default: the number of [`Calendar::weekday_names`].
+    fn weekday_count(&self) -> usize {
+        self.weekday_names().len()
+    }
+    
+    /// Get the day of the week as (index, name), with index 0 for the first of [`Calendar::weekday_names`]
+    ///
+    /// Days that are not part of any week, such as [`IntercalaryDay`]s outside the week,
+    /// return [`Calendar::weekday_count`] as their index.
+    ///
+    /// Default: the number of days between [`Calendar::week_start`] and [`Calendar::day_start`].
+    fn get_weekday(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> (usize, String) {
+        let day_start = self.day_start(elapsed_seconds, start_datetime);
+        let week_start = self.week_start(elapsed_seconds, start_datetime);
+        let index = ((day_start - week_start) / self.seconds_per_day() as f64).round() as usize;
+        let name = self.weekday_names().get(index).cloned().unwrap_or_default();
+        (index, name)
+    }
+    
+    /// Get the name of the intercalary day at a date returned by [`Calendar::get_date`], if
+    /// the date belongs to no month
+    ///
+    /// Default: `None`, the calendar has no intercalary days.
+    fn intercalary_day_name(&self, _date: (i32, u32, u32)) -> Option<String> {
+        None
+    }
+    
+    /// Get the era as (name, year counted in the era)
+    ///
+    /// Default: `None`, the calendar has no eras.
+    fn get_era(&self, _elapsed_seconds: f64, _start_datetime: NaiveDateTime) -> Option<(String, i32)> {
+        None
+    }
+    
+    /// Get the seasons of this calendar, used by [`crate::ClockInterval::Season`]
+    ///
+    /// Default: the four meteorological seasons of the northern hemisphere
+    /// (Spring from March, Summer from June, Autumn from September, Winter from December).
+    fn seasons(&self) -> Vec<Season> {
+        vec![
+            Season::new("Spring", 3),
+            Season::new("Summer", 6),
+            Season::new("Autumn", 9),
+            Season::new("Winter", 12),
+        ]
+    }
+    
+    /// Get the elapsed seconds at which a date and time begins, the inverse of
+    /// [`Calendar::get_date`] and [`Calendar::get_time`]
+    ///
+    /// Returns a [`DateError`] if the date or time does not exist in the calendar,
+    /// such as day 22 of a 21-day month.
+    ///
+    /// Default: dates never decrease as time moves on, so the day is found with an
+    /// exponential search followed by a binary search over [`Calendar::get_date`], and
+    /// the date and time are checked by converting them back.
+    fn to_elapsed(&self, date: (i32, u32, u32), time: (u32, u32, u32), start_datetime: NaiveDateTime) -> Result<f64, DateError> {
+        /// Bound for the search, far beyond the day count of any sensible date
+        const MAX_DAYS: i64 = 1 << 40;
+
+        let (year, month, day) = date;
+        let (hour, minute, second) = time;
+        let invalid_date = DateError::InvalidDate { year, month, day };
+        let seconds_per_day = self.seconds_per_day() as f64;
+        let origin = self.day_start(0.0, start_datetime);
+        let date_at = |days: i64| self.get_date(origin + days as f64 * seconds_per_day, start_datetime);
+
+        // Find days `low` and `high` with `low` on or before the date, and `high` after it
+        let (mut low, mut high) = (0, 1);
+        if date_at(0) <= date {
+            while date_at(high) <= date {
+                low = high;
+                high *= 2;
+                if high > MAX_DAYS {
+                    return Err(invalid_date);
+                }
+            }
+        } else {
+            (low, high) = (-1, 0);
+            while date_at(low) > date {
+                high = low;
+                low *= 2;
+                if low < -MAX_DAYS {
+                    return Err(invalid_date);
+                }
+            }
+        }
+
+        // Narrow down to the last day on or before the date
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if date_at(mid) <= date {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        if date_at(low) != date {
+            return Err(invalid_date);
+        }
+
+        let day_start = origin + low as f64 * seconds_per_day;
+        let seconds = (hour as u64 * self.seconds_per_hour() as u64 + minute as u64 * self.seconds_per_minute() as u64 + second as u64) as f64;
+        if seconds >= seconds_per_day || self.get_time(day_start + seconds, start_datetime) != time {
+            return Err(DateError::InvalidTime { hour, minute, second });
+        }
+        Ok(day_start + seconds)
+    }
+    
+    /// Get a serializable description of this calendar
+    ///
+    /// Default: `None` (the calendar cannot be stored in a [`crate::ClockSnapshot`]).
+    /// The built-in calendars return their definition so clocks using them can be saved.
+    fn definition(&self) -> Option<CalendarDefinition> {
+        None
+    }
+}
+
+/// Error returned when a date or time does not exist in a calendar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateError {
+    /// The month does not exist, or the day does not exist in that month and year
+    InvalidDate { year: i32, month: u32, day: u32 },
+    /// The time does not exist in a day of the calendar
+    InvalidTime { hour: u32, minute: u32, second: u32 },
+}
+
+impl std::fmt::Display for DateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateError::InvalidDate { year, month, day } => {
+                write!(f, "day {} of month {} does not exist in year {}", day, month, year)
+            }
+            DateError::InvalidTime { hour, minute, second } => {
+                write!(f, "time {:02}:{:02}:{:02} does not exist in the calendar", hour, minute, second)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateError {}
+
+/// Error returned when a [`CustomCalendar`] does not describe a valid calendar
+///
+/// Months are numbered from 1, as in dates of the calendar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalendarError {
+    /// The calendar has no months
+    NoMonths,
+    /// The calendar has no weekday names
+    NoWeekdays,
+    /// A time unit, such as `hours_per_day`, is zero
+    ZeroTimeUnit { field: &'static str },
+    /// A month has no days, even in leap years
+    EmptyMonth { month: u32, name: String },
+    /// Years without leap days, leap months or a leap week have no days
+    EmptyYear,
+    /// `epoch_weekday` is not the index of a weekday name
+    InvalidEpochWeekday { index: usize },
+    /// A leap week, intercalary day or season refers to a month that does not exist
+    UnknownMonth { field: &'static str, month: u32 },
+    /// A leap rule expression does not parse or does not evaluate to a boolean
+    InvalidExpression { field: &'static str, expression: String, message: String },
+    /// An era starts before the era listed before it
+    UnorderedEra { index: usize, name: String },
+}
+
+impl std::fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalendarError::NoMonths => write!(f, "Must have at least one month"),
+            CalendarError::NoWeekdays => write!(f, "Must have at least one weekday name"),
+            CalendarError::ZeroTimeUnit { field } => write!(f, "{} must not be zero", field),
+            CalendarError::EmptyMonth { month, name } => write!(f, "month {} ({}) has no days", month, name),
+            CalendarError::EmptyYear => write!(f, "common years have no days"),
+            CalendarError::InvalidEpochWeekday { index } => {
+                write!(f, "Epoch weekday must be the index of a weekday name, found {}", index)
+            }
+            CalendarError::UnknownMonth { field, month } => write!(f, "{} refers to month {}, which does not exist", field, month),
+            CalendarError::InvalidExpression { field, expression, message } => {
+                write!(f, "invalid {} expression '{}': {}", field, expression, message)
+            }
+            CalendarError::UnorderedEra { index, name } => {
+                write!(f, "era {} ({}) starts before the era listed before it", index, name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CalendarError {}
+
+/// A calendar seen from the start of a clock
+///
+/// Calendars count elapsed seconds from their own origin, which is `start_datetime` for
+/// [`GregorianCalendar`] and the epoch for [`CustomCalendar`]. This wrapper shifts the
+/// elapsed seconds of the clock by `offset`, so a clock can start at any date of the calendar.
+pub(crate) struct ShiftedCalendar {
+    calendar: Arc<dyn Calendar>,
+    offset: f64,
+}
+
+impl ShiftedCalendar {
+    /// Shift `calendar` by `offset`, returning it unchanged when there is nothing to shift
+    pub(crate) fn wrap(calendar: Arc<dyn Calendar>, offset: f64) -> Arc<dyn Calendar> {
+        if offset == 0.0 {
+            calendar
+        } else {
+            Arc::new(ShiftedCalendar { calendar, offset })
+        }
+    }
+}
+
+impl Calendar for ShiftedCalendar {
+    fn format_date(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime, format: Option<&str>) -> String {
+        self.calendar.format_date(elapsed_seconds + self.offset, start_datetime, format)
+    }
+
+    fn format_time(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime, format: Option<&str>) -> String {
+        self.calendar.format_time(elapsed_seconds + self.offset, start_datetime, format)
+    }
+
+    fn format_datetime(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime, format: Option<&str>) -> String {
+        self.calendar.format_datetime(elapsed_seconds + self.offset, start_datetime, format)
+    }
+
+    fn get_date(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> (i32, u32, u32) {
+        self.calendar.get_date(elapsed_seconds + self.offset, start_datetime)
+    }
+
+    fn get_time(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> (u32, u32, u32) {
+        self.calendar.get_time(elapsed_seconds + self.offset, start_datetime)
+    }
+
+    fn seconds_per_day(&self) -> u32 {
+        self.calendar.seconds_per_day()
+    }
+
+    fn seconds_per_minute(&self) -> u32 {
+        self.calendar.seconds_per_minute()
+    }
+
+    fn seconds_per_hour(&self) -> u32 {
+        self.calendar.seconds_per_hour()
+    }
+
+    fn seconds_per_week(&self) -> u32 {
+        self.calendar.seconds_per_week()
+    }
+
+    fn day_start(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> f64 {
+        self.calendar.day_start(elapsed_seconds + self.offset, start_datetime) - self.offset
+    }
+
+    fn week_start(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> f64 {
+        self.calendar.week_start(elapsed_seconds + self.offset, start_datetime) - self.offset
+    }
+
+    fn year_start(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> f64 {
+        self.calendar.year_start(elapsed_seconds + self.offset, start_datetime) - self.offset
+    }
+
+    fn intercalary_day_name(&self, date: (i32, u32, u32)) -> Option<String> {
+        self.calendar.intercalary_day_name(date)
+    }
+
+    fn to_elapsed(&self, date: (i32, u32, u32), time: (u32, u32, u32), start_datetime: NaiveDateTime) -> Result<f64, DateError> {
+        Ok(self.calendar.to_elapsed(date, time, start_datetime)? - self.offset)
+    }
+
+    fn month_names(&self) -> Vec<String> {
+        self.calendar.month_names()
+    }
+
+    fn max_days_in_month(&self) -> u32 {
+        self.calendar.max_days_in_month()
+    }
+
+    fn weekday_names(&self) -> Vec<String> {
+        self.calendar.weekday_names()
+    }
+
+    fn weekday_count(&self) -> usize {
+        self.calendar.weekday_count()
+    }
+
+    fn get_weekday(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> (usize, String) {
+        self.calendar.get_weekday(elapsed_seconds + self.offset, start_datetime)
+    }
+
+    fn get_era(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> Option<(String, i32)> {
+        self.calendar.get_era(elapsed_seconds + self.offset, start_datetime)
+    }
+
+    fn seasons(&self) -> Vec<Season> {
+        self.calendar.seasons()
+    }
+
+    fn definition(&self) -> Option<CalendarDefinition> {
+        self.calendar.definition()
+    }
+}
+
+/// Serializable description of one of the built-in calendar systems
+///
+/// Used by [`crate::ClockSnapshot`] to store the calendar of a clock in save games,
+/// since the clock itself only holds an opaque `Arc<dyn Calendar>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CalendarDefinition {
+    /// The standard [`GregorianCalendar`]
+    Gregorian,
+    /// A fully configured [`CustomCalendar`]
+    Custom(Box<CustomCalendar>),
+}
+
+impl CalendarDefinition {
+    /// Create the calendar described by this definition
+    pub fn into_calendar(self) -> Arc<dyn Calendar> {
+        match self {
+            CalendarDefinition::Gregorian => Arc::new(GregorianCalendar),
+            CalendarDefinition::Custom(calendar) => Arc::new(*calendar),
+        }
+    }
+}
+
+/// Get the date-time `elapsed_seconds` after `start_datetime`, rounded down to the millisecond
+fn offset_datetime(start_datetime: NaiveDateTime, elapsed_seconds: f64) -> NaiveDateTime {
+    start_datetime + Duration::milliseconds((elapsed_seconds * 1000.0).floor() as i64)
 }
 
 /// Default Gregorian calendar implementation using chrono
@@ -68,32 +465,50 @@ pub struct GregorianCalendar;
 
 impl Calendar for GregorianCalendar {
     fn format_date(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime, format: Option<&str>) -> String {
-        let dt = start_datetime + Duration::milliseconds((elapsed_seconds * 1000.0) as i64);
+        let dt = offset_datetime(start_datetime, elapsed_seconds);
         let fmt = format.unwrap_or("%Y-%m-%d");
         dt.format(fmt).to_string()
     }
     
     fn format_time(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime, format: Option<&str>) -> String {
-        let dt = start_datetime + Duration::milliseconds((elapsed_seconds * 1000.0) as i64);
+        let dt = offset_datetime(start_datetime, elapsed_seconds);
         let fmt = format.unwrap_or("%H:%M:%S");
         dt.format(fmt).to_string()
     }
     
     fn format_datetime(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime, format: Option<&str>) -> String {
-        let dt = start_datetime + Duration::milliseconds((elapsed_seconds * 1000.0) as i64);
+        let dt = offset_datetime(start_datetime, elapsed_seconds);
         let fmt = format.unwrap_or("%Y-%m-%d %H:%M:%S");
         dt.format(fmt).to_string()
     }
     
     fn get_date(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> (i32, u32, u32) {
-        let dt = start_datetime + Duration::milliseconds((elapsed_seconds * 1000.0) as i64);
+        let dt = offset_datetime(start_datetime, elapsed_seconds);
         (dt.year(), dt.month(), dt.day())
     }
     
     fn get_time(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> (u32, u32, u32) {
-        let dt = start_datetime + Duration::milliseconds((elapsed_seconds * 1000.0) as i64);
+        let dt = offset_datetime(start_datetime, elapsed_seconds);
         (dt.hour(), dt.minute(), dt.second())
     }
+    
+    fn get_weekday(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> (usize, String) {
+        let dt = offset_datetime(start_datetime, elapsed_seconds);
+        let index = dt.weekday().num_days_from_monday() as usize;
+        (index, self.weekday_names()[index].clone())
+    }
+    
+    fn to_elapsed(&self, date: (i32, u32, u32), time: (u32, u32, u32), start_datetime: NaiveDateTime) -> Result<f64, DateError> {
+        let (year, month, day) = date;
+        let (hour, minute, second) = time;
+        let date = chrono::NaiveDate::from_ymd_opt(year, month, day).ok_or(DateError::InvalidDate { year, month, day })?;
+        let time = chrono::NaiveTime::from_hms_opt(hour, minute, second).ok_or(DateError::InvalidTime { hour, minute, second })?;
+        Ok((NaiveDateTime::new(date, time) - start_datetime).num_milliseconds() as f64 / 1000.0)
+    }
+    
+    fn definition(&self) -> Option<CalendarDefinition> {
+        Some(CalendarDefinition::Gregorian)
+    }
 }
 
 /// Month definition combining name and length
@@ -121,6 +536,18 @@ impl Calendar for GregorianCalendar {
 /// let month = Month::new("Suntide", 21, 0);
 /// // Always 21 days regardless of leap year
 /// ```
+///
+/// # Leap Months
+///
+/// A leap month only exists in the years matched by the calendar's `leap_months` rule.
+/// Months are numbered by their position in the calendar, so a leap month keeps its
+/// number and is skipped in other years, and the months after it keep theirs too.
+///
+/// ```
+/// # use bevy_ingame_clock::Month;
+/// // A 29-day month inserted in leap-month years only
+/// let month = Month::leap_month("Adar II", 29);
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Month {
     pub name: String,
@@ -128,45 +555,613 @@ pub struct Month {
     pub days: u32,
     /// Additional days added to this month during leap years
     pub leap_days: u32,
+    /// Whether this month only exists in the years matched by the calendar's `leap_months` rule
+    #[serde(default)]
+    pub leap_month: bool,
+}
+
+impl Month {
+    pub fn new(name: impl Into<String>, days: u32, leap_days: u32) -> Self {
+        Self {
+            name: name.into(),
+            days,
+            leap_days,
+            leap_month: false,
+        }
+    }
+    
+    /// Creates a month that only exists in leap-month years
+    pub fn leap_month(name: impl Into<String>, days: u32) -> Self {
+        Self {
+            leap_month: true,
+            ..Self::new(name, days, 0)
+        }
+    }
+}
+
+/// Season definition for calendar system
+///
+/// A season starts on the first day of `start_month` (1-indexed) and lasts until the
+/// next season starts. Seasons wrap around the end of the year, so a season starting
+/// in the last month also covers the months before the first season.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ingame_clock::Season;
+/// // Winter starting in the 10th month, lasting into the next year
+/// let season = Season::new("Frostfall", 10);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Season {
+    pub name: String,
+    /// The month (1-indexed) whose first day starts this season
+    pub start_month: u32,
+}
+
+impl Season {
+    pub fn new(name: impl Into<String>, start_month: u32) -> Self {
+        Self {
+            name: name.into(),
+            start_month,
+        }
+    }
+    
+    /// Find the season containing `month` as `(season_index, season_year_offset)`
+    ///
+    /// The offset is `-1` when the month belongs to a season that started in the
+    /// previous year, and `0` otherwise. Returns `None` if there are no seasons.
+    pub(crate) fn find(seasons: &[Season], month: u32) -> Option<(usize, i32)> {
+        let current = seasons
+            .iter()
+            .enumerate()
+            .filter(|(_, season)| season.start_month <= month)
+            .max_by_key(|(_, season)| season.start_month);
+        
+        match current {
+            Some((index, _)) => Some((index, 0)),
+            None => seasons
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, season)| season.start_month)
+                .map(|(index, _)| (index, -1)),
+        }
+    }
+}
+
+/// Intercalary day definition for calendar system
+///
+/// An intercalary day is a named day that belongs to no month, such as a festival
+/// between two months or the complementary days at the end of a year. It is placed
+/// after the month `after_month` (1-indexed), or before the first month when
+/// `after_month` is 0. Days marked `leap_only` only exist in leap years.
+///
+/// Dates keep counting on from the month the day follows: the first intercalary day
+/// after a 30-day month is reported as day 31 of that month, and days before the
+/// first month as month 0. Use [`CustomCalendar::intercalary_day`] or
+/// [`Calendar::intercalary_day_name`] to tell them apart; `%B` and
+/// [`GameDateTime::month_name`](crate::GameDateTime::month_name) report their own name.
+///
+/// Intercalary days take part in the weekday rotation unless they are marked
+/// `outside_week`. Days outside the week have no weekday: the week before them lasts
+/// longer, and the next weekday continues after them.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ingame_clock::IntercalaryDay;
+/// // A festival after the 7th month, only in leap years
+/// let shieldmeet = IntercalaryDay::new("Shieldmeet", 7, true);
+///
+/// // A festival that does not interrupt the weekday rotation
+/// let midsummer = IntercalaryDay::new("Midsummer", 7, false).outside_week();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntercalaryDay {
+    pub name: String,
+    /// The month (1-indexed) after which this day takes place, 0 for before the first month
+    pub after_month: u32,
+    /// Whether this day only exists in leap years
+    #[serde(default)]
+    pub leap_only: bool,
+    /// Whether this day is left out of the weekday rotation
+    #[serde(default)]
+    pub outside_week: bool,
+}
+
+impl IntercalaryDay {
+    pub fn new(name: impl Into<String>, after_month: u32, leap_only: bool) -> Self {
+        Self {
+            name: name.into(),
+            after_month,
+            leap_only,
+            outside_week: false,
+        }
+    }
+    
+    /// Leave this day out of the weekday rotation
+    pub fn outside_week(mut self) -> Self {
+        self.outside_week = true;
+        self
+    }
+}
+
+/// Leap week definition for calendar system
+///
+/// Leap-week calendars, such as the ISO week-date calendar or Symmetry454, add a whole
+/// week to some years instead of leap days, so every year starts on the first weekday
+/// and has the same weekday layout. In the years matched by the `years` expression,
+/// the month `month` (1-indexed) gets one extra week at its end.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ingame_clock::LeapWeek;
+/// // Symmetry454: December gets a leap week in 52 out of every 293 years
+/// let leap_week = LeapWeek::new("(52 * # + 146) % 293 < 52", 12);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeapWeek {
+    /// Expression deciding which years have the leap week, using `#` as year placeholder
+    pub years: String,
+    /// The month (1-indexed) that gets the leap week
+    pub month: u32,
+}
+
+impl LeapWeek {
+    pub fn new(years: impl Into<String>, month: u32) -> Self {
+        Self {
+            years: years.into(),
+            month,
+        }
+    }
+}
+
+/// Epoch definition for calendar system
+///
+/// Represents a reference point in time for year counting, with an optional
+/// descriptive name (e.g., "Common Epoch", "Age of Magic").
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Epoch {
+    pub name: String,
+    pub start_year: i64,
+}
+
+impl Epoch {
+    pub fn new(name: impl Into<String>, start_year: i64) -> Self {
+        Self {
+            name: name.into(),
+            start_year,
+        }
+    }
+}
+
+/// Where an [`Era`] starts
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum EraStart {
+    /// The era covers all time before the next era
+    Beginning,
+    /// The era starts on the first day of a year
+    Year(i32),
+    /// The era starts at the given elapsed seconds since the epoch, such as the moment of a
+    /// historic event. Its first year is the year containing that moment.
+    Elapsed(f64),
+}
+
+/// Direction in which the years of an [`Era`] are counted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EraDirection {
+    /// Years count up from 1 in the first year of the era
+    #[default]
+    Forward,
+    /// Years count down to 1 in the last year before the next era, as in "312 BE"
+    Backward,
+}
+
+/// Named era of a calendar, such as "First Age" or "Before Epoch"
+///
+/// Eras only rename years for display, the date of [`Calendar::get_date`] keeps counting
+/// years from the [`Epoch`]. Each era lasts until the next era of the calendar starts.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ingame_clock::{Era, EraStart};
+/// let before = Era::new("BE", EraStart::Beginning).backward();
+/// let first_age = Era::new("First Age", EraStart::Year(1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Era {
+    pub name: String,
+    pub start: EraStart,
+    #[serde(default)]
+    pub direction: EraDirection,
+}
+
+impl Era {
+    pub fn new(name: impl Into<String>, start: EraStart) -> Self {
+        Self {
+            name: name.into(),
+            start,
+            direction: EraDirection::Forward,
+        }
+    }
+    
+    /// Count the years of this era down to the start of the next era
+    pub fn backward(mut self) -> Self {
+        self.direction = EraDirection::Backward;
+        self
+    }
+}
+
+/// Clamp a year count to the range of years of [`Calendar::get_date`]
+fn clamp_year(year: i64) -> i32 {
+    year.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// Default minute length: 60 seconds
+fn default_seconds_per_minute() -> u32 {
+    60
+}
+
+/// Default leap year rule: no leap years
+fn default_leap_years() -> String {
+    "false".to_string()
+}
+
+/// Returns default epoch (Common Epoch starting at year 1)
+fn default_epoch() -> Epoch {
+    Epoch::new("Common Epoch", 1)
+}
+
+/// Years between two entries of a [`YearTable`]
+const TABLE_CHUNK: i64 = 16;
+
+/// Years on each side of the epoch that a [`YearTable`] computes from the leap rules
+const MAX_TABLE_YEARS: i64 = 1 << 22;
+
+/// Longest cycle of year lengths, in years, repeated past [`MAX_TABLE_YEARS`]
+const MAX_LEAP_CYCLE: i64 = 1000;
+
+/// Calendar fields that determine the length of every year
+#[derive(Debug, Clone)]
+struct YearCacheKey {
+    leap_years: String,
+    leap_months: Option<String>,
+    months: Vec<(u32, u32, bool)>,
+    intercalary_days: Vec<(bool, bool)>,
+    leap_week: Option<LeapWeek>,
+    week_length: usize,
+    start_year: i64,
+}
+
+impl YearCacheKey {
+    fn of(calendar: &CustomCalendar) -> Self {
+        Self {
+            leap_years: calendar.leap_years.clone(),
+            leap_months: calendar.leap_months.clone(),
+            months: calendar.months.iter().map(|month| (month.days, month.leap_days, month.leap_month)).collect(),
+            intercalary_days: calendar.intercalary_days.iter().map(|day| (day.leap_only, day.outside_week)).collect(),
+            leap_week: calendar.leap_week.clone(),
+            week_length: calendar.weekdays.len(),
+            start_year: calendar.epoch.start_year,
+        }
+    }
+
+    fn matches(&self, calendar: &CustomCalendar) -> bool {
+        self.leap_years == calendar.leap_years
+            && self.leap_months == calendar.leap_months
+            && self.start_year == calendar.epoch.start_year
+            && self.months.iter().copied().eq(calendar.months.iter().map(|month| (month.days, month.leap_days, month.leap_month)))
+            && self.intercalary_days.iter().copied().eq(calendar.intercalary_days.iter().map(|day| (day.leap_only, day.outside_week)))
+            && self.leap_week == calendar.leap_week
+            && self.week_length == calendar.weekdays.len()
+    }
+}
+
+/// Which leap rules of a [`CustomCalendar`] apply to a year
+#[derive(Debug, Clone, Copy)]
+struct YearRules {
+    leap_year: bool,
+    leap_months: bool,
+    leap_week: bool,
+}
+
+/// Days, and days outside the week, of a run of years
+type YearCounts = (i64, i64);
+
+/// Cumulative counts of the years on one side of the epoch, going away from it
+///
+/// Year `0` of the run after the epoch is the epoch year, year `0` of the run before it
+/// is the year before the epoch year.
+#[derive(Debug, Clone)]
+struct YearRun {
+    backward: bool,
+    /// Counts of the first `i * TABLE_CHUNK` years of the run, for every chunk computed so far
+    chunks: Vec<YearCounts>,
+    /// Cumulative counts of the years repeated after [`MAX_TABLE_YEARS`], once needed
+    cycle: Option<Vec<YearCounts>>,
+}
+
+impl YearRun {
+    fn new(backward: bool) -> Self {
+        Self {
+            backward,
+            chunks: vec![(0, 0)],
+            cycle: None,
+        }
+    }
+    
+    /// Get the counts of a year of the run from the leap rules
+    ///
+    /// The rules are evaluated without remembering their results, which would otherwise
+    /// fill the memo of the expressions with years computed only once.
+    fn rule_year(&self, calendar: &CustomCalendar, index: i64) -> YearCounts {
+        let offset = if self.backward { -1 - index } else { index };
+        let rules = calendar.leap_rules().evaluate(clamp_year(calendar.epoch.start_year + offset));
+        let days: u32 = calendar.period_lengths(rules).sum();
+        (days as i64, calendar.days_outside_week(rules.leap_year) as i64)
+    }
+    
+    /// Get the counts of a year of the run, repeating the cycle past [`MAX_TABLE_YEARS`]
+    fn year(&mut self, calendar: &CustomCalendar, index: i64) -> YearCounts {
+        if index < MAX_TABLE_YEARS {
+            return self.rule_year(calendar, index);
+        }
+        let cycle = self.cycle(calendar);
+        let position = ((index - MAX_TABLE_YEARS) % (cycle.len() as i64 - 1)) as usize;
+        (cycle[position + 1].0 - cycle[position].0, cycle[position + 1].1 - cycle[position].1)
+    }
+    
+    /// Compute the next chunk of the table
+    fn push_chunk(&mut self, calendar: &CustomCalendar) {
+        let first = (self.chunks.len() as i64 - 1) * TABLE_CHUNK;
+        let (mut days, mut outside_week) = self.chunks[self.chunks.len() - 1];
+        for index in first..first + TABLE_CHUNK {
+            let year = self.rule_year(calendar, index);
+            days += year.0;
+            outside_week += year.1;
+        }
+        self.chunks.push((days, outside_week));
+    }
+    
+    /// Get the cycle of the last years of the table, repeated after them
+    ///
+    /// The cycle is the shortest one the last `2 * MAX_LEAP_CYCLE` years follow, or the
+    /// last [`MAX_LEAP_CYCLE`] years if they follow none.
+    fn cycle(&mut self, calendar: &CustomCalendar) -> &[YearCounts] {
+        if self.cycle.is_none() {
+            let years: Vec<YearCounts> = (MAX_TABLE_YEARS - 2 * MAX_LEAP_CYCLE..MAX_TABLE_YEARS)
+                .map(|index| self.rule_year(calendar, index))
+                .collect();
+            let cycle_years = (1..MAX_LEAP_CYCLE as usize)
+                .find(|&cycle_years| (0..years.len() - cycle_years).all(|i| years[i] == years[i + cycle_years]))
+                .unwrap_or(MAX_LEAP_CYCLE as usize);
+            let starts = std::iter::once((0, 0))
+                .chain(years[years.len() - cycle_years..].iter().scan((0, 0), |start, year| {
+                    *start = (start.0 + year.0, start.1 + year.1);
+                    Some(*start)
+                }))
+                .collect();
+            self.cycle = Some(starts);
+        }
+        self.cycle.as_deref().unwrap()
+    }
+    
+    /// Get the counts of the first `years` years of the run
+    fn counts(&mut self, calendar: &CustomCalendar, years: i64) -> YearCounts {
+        let table_years = years.min(MAX_TABLE_YEARS);
+        let chunk = table_years / TABLE_CHUNK;
+        while self.chunks.len() as i64 <= chunk {
+            self.push_chunk(calendar);
+        }
+        let mut counts = self.chunks[chunk as usize];
+        let mut add = |year: YearCounts| counts = (counts.0 + year.0, counts.1 + year.1);
+        for index in chunk * TABLE_CHUNK..table_years {
+            add(self.rule_year(calendar, index));
+        }
+        if years > table_years {
+            let cycle = self.cycle(calendar);
+            let cycle_years = cycle.len() as i64 - 1;
+            let (whole, part) = ((years - table_years) / cycle_years, ((years - table_years) % cycle_years) as usize);
+            let (cycle_days, cycle_outside_week) = cycle[cycle.len() - 1];
+            add((whole * cycle_days + cycle[part].0, whole * cycle_outside_week + cycle[part].1));
+        }
+        counts
+    }
+    
+    /// Split `days` (at least 0) into the number of whole years of the run they span and
+    /// the days left in the next year
+    fn split(&mut self, calendar: &CustomCalendar, days: i64) -> (i64, i64) {
+        let table_chunks = MAX_TABLE_YEARS / TABLE_CHUNK;
+        while self.chunks[self.chunks.len() - 1].0 <= days && (self.chunks.len() as i64) <= table_chunks {
+            self.push_chunk(calendar);
+        }
+        let table_days = self.chunks[self.chunks.len() - 1].0;
+        
+        // Skip whole cycles past the end of the table
+        let (mut index, mut remaining) = if table_days <= days {
+            let cycle = self.cycle(calendar);
+            let cycle_days = cycle[cycle.len() - 1].0;
+            if cycle_days == 0 {
+                // Invalid calendars whose years have no days never reach the next day
+                return (MAX_TABLE_YEARS, days - table_days);
+            }
+            let whole = (days - table_days) / cycle_days;
+            (MAX_TABLE_YEARS + whole * (cycle.len() as i64 - 1), days - table_days - whole * cycle_days)
+        } else {
+            let chunk = self.chunks.partition_point(|counts| counts.0 <= days) - 1;
+            (chunk as i64 * TABLE_CHUNK, days - self.chunks[chunk].0)
+        };
+        loop {
+            let length = self.year(calendar, index).0;
+            if remaining < length {
+                return (index, remaining);
+            }
+            remaining -= length;
+            index += 1;
+        }
+    }
+}
+
+/// Day counts from the start of the epoch to the start of the years of a [`CustomCalendar`]
+///
+/// Chunks of years are computed from the leap rules as far from the epoch as dates are
+/// queried, so that rules which change over time, such as a calendar reform, are followed
+/// exactly. Past [`MAX_TABLE_YEARS`] on either side, the cycle of year lengths found at
+/// the end of the table is assumed to repeat.
+#[derive(Debug, Clone)]
+struct YearTable {
+    key: YearCacheKey,
+    after_epoch: YearRun,
+    before_epoch: YearRun,
 }
 
-impl Month {
-    pub fn new(name: impl Into<String>, days: u32, leap_days: u32) -> Self {
+impl YearTable {
+    fn new(calendar: &CustomCalendar) -> Self {
         Self {
-            name: name.into(),
-            days,
-            leap_days,
+            key: YearCacheKey::of(calendar),
+            after_epoch: YearRun::new(false),
+            before_epoch: YearRun::new(true),
         }
     }
 }
 
-/// Epoch definition for calendar system
+/// Lazily filled year table of a [`CustomCalendar`], cleared when its fields change
+#[derive(Debug, Default)]
+struct YearCache(Mutex<Option<YearTable>>);
+
+impl Clone for YearCache {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.0.lock().unwrap().clone()))
+    }
+}
+
+/// Most years whose leap rule results are remembered, before the results are cleared
+const MAX_MEMOIZED_YEARS: usize = 1 << 16;
+
+#[cfg(test)]
+thread_local! {
+    /// Number of leap rule expressions parsed on this thread
+    static PARSED_EXPRESSIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Evaluation context binding the `year` variable of a leap rule expression
+struct YearContext(Value);
+
+impl Context for YearContext {
+    type NumericTypes = DefaultNumericTypes;
+    
+    fn get_value(&self, identifier: &str) -> Option<&Value> {
+        (identifier == "year").then_some(&self.0)
+    }
+    
+    fn call_function(&self, identifier: &str, _argument: &Value) -> EvalexprResult<Value> {
+        Err(EvalexprError::FunctionIdentifierNotFound(identifier.to_string()))
+    }
+    
+    fn are_builtin_functions_disabled(&self) -> bool {
+        false
+    }
+    
+    fn set_builtin_functions_disabled(&mut self, disabled: bool) -> EvalexprResult<()> {
+        if disabled {
+            Err(EvalexprError::BuiltinFunctionsCannotBeDisabled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Leap rule expression parsed once, with its results remembered per year
 ///
-/// Represents a reference point in time for year counting, with an optional
-/// descriptive name (e.g., "Common Epoch", "Age of Magic").
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Epoch {
-    pub name: String,
-    pub start_year: i64,
+/// The `#` placeholder is parsed as the `year` variable, bound when evaluating.
+#[derive(Debug)]
+struct CompiledExpression {
+    source: String,
+    /// The parsed expression, or the parse error message
+    node: Result<Node, String>,
+    results: Mutex<HashMap<i32, bool>>,
 }
 
-impl Epoch {
-    pub fn new(name: impl Into<String>, start_year: i64) -> Self {
+impl CompiledExpression {
+    fn new(source: &str) -> Self {
+        #[cfg(test)]
+        PARSED_EXPRESSIONS.with(|count| count.set(count.get() + 1));
+        
         Self {
-            name: name.into(),
-            start_year,
+            source: source.to_string(),
+            node: build_operator_tree(&source.replace("#", "year")).map_err(|error| error.to_string()),
+            results: Mutex::new(HashMap::new()),
+        }
+    }
+    
+    /// Evaluate the expression for a year, without using remembered results
+    fn evaluate(&self, year: i32) -> Result<bool, String> {
+        let node = self.node.as_ref().map_err(Clone::clone)?;
+        node.eval_boolean_with_context(&YearContext(Value::Int(year as i64)))
+            .map_err(|error| error.to_string())
+    }
+    
+    /// Check whether the expression holds for a year, counting failed evaluations as false
+    fn matches(&self, year: i32) -> bool {
+        let mut results = self.results.lock().unwrap();
+        if let Some(result) = results.get(&year) {
+            return *result;
         }
+        if results.len() >= MAX_MEMOIZED_YEARS {
+            results.clear();
+        }
+        let result = self.evaluate(year).unwrap_or(false);
+        results.insert(year, result);
+        result
     }
 }
 
-/// Default leap year rule: no leap years
-fn default_leap_years() -> String {
-    "false".to_string()
+/// Compiled leap rules of a [`CustomCalendar`]
+#[derive(Debug)]
+struct LeapRules {
+    leap_years: CompiledExpression,
+    leap_months: Option<CompiledExpression>,
+    leap_week: Option<CompiledExpression>,
 }
 
-/// Returns default epoch (Common Epoch starting at year 1)
-fn default_epoch() -> Epoch {
-    Epoch::new("Common Epoch", 1)
+impl LeapRules {
+    fn compile(leap_years: &str, leap_months: Option<&str>, leap_week: Option<&LeapWeek>) -> Self {
+        Self {
+            leap_years: CompiledExpression::new(leap_years),
+            leap_months: leap_months.map(CompiledExpression::new),
+            leap_week: leap_week.map(|leap_week| CompiledExpression::new(&leap_week.years)),
+        }
+    }
+    
+    /// Get the rules applying to a year, without remembering the results
+    fn evaluate(&self, year: i32) -> YearRules {
+        let leap_year = self.leap_years.evaluate(year).unwrap_or(false);
+        YearRules {
+            leap_year,
+            leap_months: self.leap_months.as_ref().map_or(leap_year, |expression| expression.evaluate(year).unwrap_or(false)),
+            leap_week: self.leap_week.as_ref().is_some_and(|expression| expression.evaluate(year).unwrap_or(false)),
+        }
+    }
+    
+    /// Check that the rules were compiled from the current expressions of the calendar
+    fn matches(&self, calendar: &CustomCalendar) -> bool {
+        self.leap_years.source == calendar.leap_years
+            && self.leap_months.as_ref().map(|expression| expression.source.as_str()) == calendar.leap_months.as_deref()
+            && self.leap_week.as_ref().map(|expression| expression.source.as_str())
+                == calendar.leap_week.as_ref().map(|leap_week| leap_week.years.as_str())
+    }
+}
+
+/// Compiled leap rules of a [`CustomCalendar`], compiled again when its expressions change
+#[derive(Debug)]
+struct RuleCache(Mutex<Arc<LeapRules>>);
+
+impl Clone for RuleCache {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(Arc::clone(&self.0.lock().unwrap())))
+    }
 }
 
 /// Custom calendar with fully configurable time units and structure
@@ -208,11 +1203,23 @@ fn default_epoch() -> Epoch {
 /// 2. **Leap Day Distribution**: Each month can specify extra days (`leap_days`) gained
 ///    during leap years, allowing flexible distribution across months.
 ///
-/// 3. **Total Year Length**: Normal year = sum of `days`; Leap year = sum of `(days + leap_days)`.
+/// 3. **Total Year Length**: Normal year = sum of `days`; Leap year = sum of `(days + leap_days)`,
+///    plus any [`IntercalaryDay`]s of that year.
+///
+/// Dates are computed from the real length of every year, so rules that change over time,
+/// such as `"# % 4 == 0 && # < 3000"`, are followed exactly. The running totals of year
+/// lengths are remembered in chunks of years as far from the epoch as dates are looked up,
+/// so each year is computed once and later lookups only walk a few years. More than four
+/// million years from the epoch, the year lengths are assumed to keep repeating the cycle
+/// found just before.
 ///
 /// See [`CustomCalendar::builder()`](CustomCalendar::builder) for usage examples.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "CalendarFields")]
 pub struct CustomCalendar {
+    /// Number of seconds in one minute
+    #[serde(default = "default_seconds_per_minute")]
+    pub seconds_per_minute: u32,
     /// Number of minutes in one hour
     pub minutes_per_hour: u32,
     /// Number of hours in one day
@@ -225,10 +1232,90 @@ pub struct CustomCalendar {
     pub weekdays: Vec<String>,
     /// Leap year expression: a boolean expression using `#` as year placeholder.
     /// Examples: `"false"`, `"# % 4 == 0"`, `"# % 4 == 0 && (# % 100 != 0 || # % 400 == 0)"`
+    ///
     #[serde(default = "default_leap_years")]
     pub leap_years: String,
+    /// Leap month expression deciding in which years the months marked `leap_month` exist,
+    /// with the same syntax as `leap_years`. `None` uses the leap years.
+    #[serde(default)]
+    pub leap_months: Option<String>,
     /// The epoch information for this calendar (reference point for year counting)
     pub epoch: Epoch,
+    /// Index into `weekdays` of the first day of the epoch
+    #[serde(default)]
+    pub epoch_weekday: usize,
+    /// The seasons of the year, used by [`crate::ClockInterval::Season`].
+    /// Empty if the calendar has no seasons.
+    #[serde(default)]
+    pub seasons: Vec<Season>,
+    /// Named days that belong to no month, in the order they occur
+    #[serde(default)]
+    pub intercalary_days: Vec<IntercalaryDay>,
+    /// Leap week rule. When set, every year starts on the first weekday and `epoch_weekday`
+    /// is not used.
+    #[serde(default)]
+    pub leap_week: Option<LeapWeek>,
+    /// Named eras in chronological order, used by the `%E`, `%Ey` and `%EY` format tokens.
+    /// Empty to count years from the epoch.
+    #[serde(default)]
+    pub eras: Vec<Era>,
+    #[serde(skip)]
+    year_cache: YearCache,
+    /// The leap rule expressions, compiled when the calendar is built or deserialized
+    #[serde(skip)]
+    leap_rules: RuleCache,
+}
+
+/// Fields of a [`CustomCalendar`] as written in RON or JSON
+///
+/// Deserializing goes through this struct so that the leap rules are compiled once
+/// the fields are known.
+#[derive(Deserialize)]
+struct CalendarFields {
+    #[serde(default = "default_seconds_per_minute")]
+    seconds_per_minute: u32,
+    minutes_per_hour: u32,
+    hours_per_day: u32,
+    months: Vec<Month>,
+    weekdays: Vec<String>,
+    #[serde(default = "default_leap_years")]
+    leap_years: String,
+    #[serde(default)]
+    leap_months: Option<String>,
+    epoch: Epoch,
+    #[serde(default)]
+    epoch_weekday: usize,
+    #[serde(default)]
+    seasons: Vec<Season>,
+    #[serde(default)]
+    intercalary_days: Vec<IntercalaryDay>,
+    #[serde(default)]
+    leap_week: Option<LeapWeek>,
+    #[serde(default)]
+    eras: Vec<Era>,
+}
+
+impl From<CalendarFields> for CustomCalendar {
+    fn from(fields: CalendarFields) -> Self {
+        let leap_rules = LeapRules::compile(&fields.leap_years, fields.leap_months.as_deref(), fields.leap_week.as_ref());
+        Self {
+            seconds_per_minute: fields.seconds_per_minute,
+            minutes_per_hour: fields.minutes_per_hour,
+            hours_per_day: fields.hours_per_day,
+            months: fields.months,
+            weekdays: fields.weekdays,
+            leap_years: fields.leap_years,
+            leap_months: fields.leap_months,
+            epoch: fields.epoch,
+            epoch_weekday: fields.epoch_weekday,
+            seasons: fields.seasons,
+            intercalary_days: fields.intercalary_days,
+            leap_week: fields.leap_week,
+            eras: fields.eras,
+            year_cache: YearCache::default(),
+            leap_rules: RuleCache(Mutex::new(Arc::new(leap_rules))),
+        }
+    }
 }
 
 /// Builder for creating a [`CustomCalendar`] with a fluent API
@@ -239,12 +1326,19 @@ pub struct CustomCalendar {
 /// See [`CustomCalendar::builder()`](CustomCalendar::builder) for usage examples.
 #[derive(Debug, Clone, Default)]
 pub struct CustomCalendarBuilder {
+    seconds_per_minute: Option<u32>,
     minutes_per_hour: Option<u32>,
     hours_per_day: Option<u32>,
     months: Vec<Month>,
     weekdays: Vec<String>,
     leap_years: Option<String>,
+    leap_months: Option<String>,
     epoch: Option<Epoch>,
+    epoch_weekday: usize,
+    seasons: Vec<Season>,
+    intercalary_days: Vec<IntercalaryDay>,
+    leap_week: Option<LeapWeek>,
+    eras: Vec<Era>,
 }
 
 impl CustomCalendarBuilder {
@@ -253,6 +1347,17 @@ impl CustomCalendarBuilder {
         Self::default()
     }
     
+    /// Set the number of seconds per minute
+    pub fn seconds_per_minute(mut self, seconds: u32) -> Self {
+        self.seconds_per_minute = Some(seconds);
+        self
+    }
+    
+    /// Use decimal time: 10 hours per day, 100 minutes per hour and 100 seconds per minute
+    pub fn decimal_time(self) -> Self {
+        self.hours_per_day(10).minutes_per_hour(100).seconds_per_minute(100)
+    }
+    
     /// Set the number of minutes per hour
     pub fn minutes_per_hour(mut self, minutes: u32) -> Self {
         self.minutes_per_hour = Some(minutes);
@@ -295,39 +1400,126 @@ impl CustomCalendarBuilder {
         self
     }
     
+    /// Set the expression deciding which years have the leap months, using `#` as year placeholder
+    pub fn leap_months(mut self, expression: impl Into<String>) -> Self {
+        self.leap_months = Some(expression.into());
+        self
+    }
+    
     /// Set the epoch/epoch for the calendar
     pub fn epoch(mut self, epoch: Epoch) -> Self {
         self.epoch = Some(epoch);
         self
     }
     
-    /// Build the custom calendar
+    /// Set the weekday of the first day of the epoch, as an index into the weekday names
+    pub fn epoch_weekday(mut self, index: usize) -> Self {
+        self.epoch_weekday = index;
+        self
+    }
+    
+    /// Add a season to the calendar
+    pub fn season(mut self, season: Season) -> Self {
+        self.seasons.push(season);
+        self
+    }
+    
+    /// Set all seasons at once
+    pub fn seasons(mut self, seasons: Vec<Season>) -> Self {
+        self.seasons = seasons;
+        self
+    }
+    
+    /// Add an intercalary day to the calendar
+    pub fn intercalary_day(mut self, day: IntercalaryDay) -> Self {
+        self.intercalary_days.push(day);
+        self
+    }
+    
+    /// Set all intercalary days at once
+    pub fn intercalary_days(mut self, days: Vec<IntercalaryDay>) -> Self {
+        self.intercalary_days = days;
+        self
+    }
+    
+    /// Add a leap week to some years, so that every year starts on the first weekday
+    pub fn leap_week(mut self, leap_week: LeapWeek) -> Self {
+        self.leap_week = Some(leap_week);
+        self
+    }
+    
+    /// Add an era to the calendar, after the eras added before
+    pub fn era(mut self, era: Era) -> Self {
+        self.eras.push(era);
+        self
+    }
+    
+    /// Set all eras at once, in chronological order
+    pub fn eras(mut self, eras: Vec<Era>) -> Self {
+        self.eras = eras;
+        self
+    }
+    
+    /// Build the custom calendar, panicking if it is not valid
+    ///
+    /// See [`CustomCalendarBuilder::try_build`] for the defaults and the checks.
+    ///
+    /// # Panics
+    /// Panics with the [`CalendarError`] if the calendar is not valid, such as when no
+    /// months or weekday names were added
+    pub fn build(self) -> CustomCalendar {
+        self.try_build().unwrap_or_else(|error| panic!("{}", error))
+    }
+    
+    /// Build the custom calendar, checking it with [`CustomCalendar::validate`]
     ///
     /// # Defaults
+    /// - `seconds_per_minute`: 60
     /// - `minutes_per_hour`: 60
     /// - `hours_per_day`: 24
     /// - `leap_years`: `"false"`
+    /// - `leap_months`: the leap years
     /// - `epoch`: "Common Epoch" starting at year 1
+    /// - `epoch_weekday`: 0, the epoch starts on the first weekday
+    /// - `seasons`: none
+    /// - `intercalary_days`: none
+    /// - `leap_week`: none, weekdays rotate freely across years
+    /// - `eras`: none, years count from the epoch
     ///
-    /// # Panics
-    /// Panics if no months or weekday names were added
-    pub fn build(self) -> CustomCalendar {
+    /// # Examples
+    /// ```
+    /// # use bevy_ingame_clock::{CalendarError, CustomCalendar, Month};
+    /// let result = CustomCalendar::builder()
+    ///     .hours_per_day(0)
+    ///     .month(Month::new("Frostmoon", 20, 0))
+    ///     .weekday("Moonday")
+    ///     .try_build();
+    /// assert_eq!(result.unwrap_err(), CalendarError::ZeroTimeUnit { field: "hours_per_day" });
+    /// ```
+    pub fn try_build(self) -> Result<CustomCalendar, CalendarError> {
+        let seconds_per_minute = self.seconds_per_minute.unwrap_or_else(default_seconds_per_minute);
         let minutes_per_hour = self.minutes_per_hour.unwrap_or(60);
         let hours_per_day = self.hours_per_day.unwrap_or(24);
         let leap_years = self.leap_years.unwrap_or_else(default_leap_years);
         let epoch = self.epoch.unwrap_or_else(default_epoch);
         
-        assert!(!self.months.is_empty(), "Must have at least one month");
-        assert!(!self.weekdays.is_empty(), "Must have at least one weekday name");
-        
-        CustomCalendar {
+        let calendar = CustomCalendar::from(CalendarFields {
+            seconds_per_minute,
             minutes_per_hour,
             hours_per_day,
             months: self.months,
             weekdays: self.weekdays,
             leap_years,
+            leap_months: self.leap_months,
             epoch,
-        }
+            epoch_weekday: self.epoch_weekday,
+            seasons: self.seasons,
+            intercalary_days: self.intercalary_days,
+            leap_week: self.leap_week,
+            eras: self.eras,
+        });
+        calendar.validate()?;
+        Ok(calendar)
     }
 }
 
@@ -354,81 +1546,546 @@ impl CustomCalendar {
         CustomCalendarBuilder::default()
     }
     
-    fn days_per_year(&self) -> u32 {
-        self.months.iter().map(|m| m.days).sum()
+    /// The French Republican calendar, with decimal time
+    ///
+    /// Twelve months of 30 days are followed by five complementary days, and a sixth
+    /// in leap years. Days have 10 hours of 100 minutes of 100 seconds, and weeks are
+    /// 10-day décades; the complementary days are outside the décades, so every month
+    /// starts on a Primidi. Year 1 began on 22 September 1792. Years 3, 7, 11 and 15 are leap
+    /// years, as they were historically, and from year 20 on the Gregorian rule applies,
+    /// as proposed by Romme.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ingame_clock::{Calendar, CustomCalendar, InGameClock};
+    /// let clock = InGameClock::new()
+    ///     .with_calendar(CustomCalendar::french_republican())
+    ///     .with_calendar_start(2, 12, 31, 5, 0, 0)
+    ///     .unwrap();
+    /// assert_eq!(clock.format_datetime(Some("%B, an %Y")), "Jour de la vertu, an 2");
+    /// assert_eq!(clock.format_time(None), "05:00:00");
+    /// ```
+    pub fn french_republican() -> Self {
+        let months = [
+            "Vendémiaire", "Brumaire", "Frimaire", "Nivôse", "Pluviôse", "Ventôse",
+            "Germinal", "Floréal", "Prairial", "Messidor", "Thermidor", "Fructidor",
+        ];
+        let weekdays = ["Primidi", "Duodi", "Tridi", "Quartidi", "Quintidi", "Sextidi", "Septidi", "Octidi", "Nonidi", "Décadi"];
+        let complementary_days = [
+            "Jour de la vertu", "Jour du génie", "Jour du travail", "Jour de l'opinion", "Jour des récompenses",
+        ];
+        
+        CustomCalendar::builder()
+            .decimal_time()
+            .months(months.iter().map(|name| Month::new(*name, 30, 0)).collect())
+            .weekdays(weekdays.iter().map(|name| name.to_string()).collect())
+            .intercalary_days(
+                complementary_days
+                    .iter()
+                    .map(|name| IntercalaryDay::new(*name, 12, false).outside_week())
+                    .collect(),
+            )
+            .intercalary_day(IntercalaryDay::new("Jour de la Révolution", 12, true).outside_week())
+            .leap_years("(# <= 15 && # % 4 == 3) || (# >= 20 && # % 4 == 0 && (# % 100 != 0 || # % 400 == 0))")
+            .epoch(Epoch::new("Ère républicaine", 1))
+            .seasons(vec![
+                Season::new("Automne", 1),
+                Season::new("Hiver", 4),
+                Season::new("Printemps", 7),
+                Season::new("Été", 10),
+            ])
+            .build()
+    }
+    
+    /// Get the number of days in the given year, including leap days and intercalary days
+    pub fn days_in_year(&self, year: i32) -> u32 {
+        self.period_lengths(self.year_rules(year)).sum()
+    }
+    
+    /// Get the number of days in the given month (1-based) of the given year, without intercalary days
+    ///
+    /// Leap months have no days in the years without leap months.
+    pub fn days_in_month(&self, year: i32, month: u32) -> u32 {
+        let index = (month as usize).wrapping_sub(1);
+        if index < self.months.len() {
+            self.month_length(index, self.year_rules(year))
+        } else {
+            0
+        }
+    }
+    
+    /// Get the length of the month at `index` (0-based) in a year with the given rules
+    fn month_length(&self, index: usize, rules: YearRules) -> u32 {
+        let month = &self.months[index];
+        let mut days = if month.leap_month && !rules.leap_months {
+            0
+        } else if rules.leap_year {
+            month.days + month.leap_days
+        } else {
+            month.days
+        };
+        if rules.leap_week && self.leap_week.as_ref().is_some_and(|leap_week| leap_week.month as usize == index + 1) {
+            days += self.weekdays.len() as u32;
+        }
+        days
+    }
+    
+    fn year_rules(&self, year: i32) -> YearRules {
+        YearRules {
+            leap_year: self.is_leap_year(year),
+            leap_months: self.has_leap_months(year),
+            leap_week: self.has_leap_week(year),
+        }
+    }
+    
+    /// Get the intercalary days of a year taking place after the month `after_month`
+    fn intercalary_days_after(&self, after_month: u32, is_leap_year: bool) -> impl Iterator<Item = &IntercalaryDay> {
+        let last_month = self.months.len() as u32;
+        self.intercalary_days
+            .iter()
+            .filter(move |day| day.after_month.min(last_month) == after_month && (is_leap_year || !day.leap_only))
+    }
+    
+    /// Get the number of days reported under each month of a year, starting with the
+    /// intercalary days before the first month as month 0
+    fn period_lengths(&self, rules: YearRules) -> impl Iterator<Item = u32> + '_ {
+        (0..=self.months.len() as u32).map(move |month| {
+            let days = month.checked_sub(1).map_or(0, |index| self.month_length(index as usize, rules));
+            days + self.intercalary_days_after(month, rules.leap_year).count() as u32
+        })
+    }
+    
+    /// Get the intercalary day at a date, if the date is not part of its month
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ingame_clock::CustomCalendar;
+    /// let calendar = CustomCalendar::french_republican();
+    /// assert_eq!(calendar.intercalary_day((2, 12, 32)).unwrap().name, "Jour du génie");
+    /// assert!(calendar.intercalary_day((2, 12, 30)).is_none());
+    /// ```
+    pub fn intercalary_day(&self, date: (i32, u32, u32)) -> Option<&IntercalaryDay> {
+        let (year, month, day) = date;
+        let index = day.checked_sub(self.days_in_month(year, month) + 1)?;
+        self.intercalary_days_after(month, self.is_leap_year(year)).nth(index as usize)
+    }
+    
+    /// Get the name `%B` formats for a date: the month name, or the name of an intercalary day
+    fn period_name(&self, date: (i32, u32, u32)) -> String {
+        match self.intercalary_day(date) {
+            Some(day) => day.name.clone(),
+            None => self.months.get((date.1 as usize).wrapping_sub(1)).map(|month| month.name.clone()).unwrap_or_default(),
+        }
+    }
+    
+    /// Get the compiled leap rules, compiling them again if the expressions were changed
+    fn leap_rules(&self) -> Arc<LeapRules> {
+        let mut rules = self.leap_rules.0.lock().unwrap();
+        if !rules.matches(self) {
+            *rules = Arc::new(LeapRules::compile(&self.leap_years, self.leap_months.as_deref(), self.leap_week.as_ref()));
+        }
+        Arc::clone(&rules)
+    }
+    
+    /// Run `f` on the year table, clearing it first if the calendar changed
+    fn with_year_table<R>(&self, f: impl FnOnce(&mut YearTable) -> R) -> R {
+        let mut cache = self.year_cache.0.lock().unwrap();
+        let table = match cache.as_mut() {
+            Some(table) if table.key.matches(self) => table,
+            _ => cache.insert(YearTable::new(self)),
+        };
+        f(table)
+    }
+    
+    /// Get the days, and days outside the week, from the start of the epoch to the start
+    /// of the given year, negative for years before the epoch
+    fn counts_before_year(&self, year: i32) -> YearCounts {
+        let years = year as i64 - self.epoch.start_year;
+        self.with_year_table(|table| {
+            if years >= 0 {
+                table.after_epoch.counts(self, years)
+            } else {
+                let (days, outside_week) = table.before_epoch.counts(self, -years);
+                (-days, -outside_week)
+            }
+        })
+    }
+    
+    /// Get the number of days from the start of the epoch to the start of the given year
+    fn days_before_year(&self, year: i32) -> i64 {
+        self.counts_before_year(year).0
+    }
+    
+    fn has_days_outside_week(&self) -> bool {
+        self.intercalary_days.iter().any(|day| day.outside_week)
+    }
+    
+    /// Get the number of intercalary days outside the week in a common or leap year
+    fn days_outside_week(&self, is_leap_year: bool) -> u32 {
+        if !self.has_days_outside_week() {
+            return 0;
+        }
+        self.intercalary_days
+            .iter()
+            .filter(|day| day.outside_week && (is_leap_year || !day.leap_only))
+            .count() as u32
+    }
+    
+    /// Get the weekday index of a day since the epoch, or its intercalary day if the day is outside the week
+    fn weekday_of_day(&self, total_days: i64) -> Result<usize, &IntercalaryDay> {
+        let weekdays = self.weekdays.len() as i64;
+        if self.leap_week.is_none() && !self.has_days_outside_week() {
+            return Ok((total_days + self.epoch_weekday as i64).rem_euclid(weekdays) as usize);
+        }
+        
+        let (year, day_of_year) = self.year_and_day(total_days);
+        let (outside_week, day) = self.outside_week_in_year(year, day_of_year);
+        if let Some(day) = day {
+            return Err(day);
+        }
+        let days_in_week = day_of_year as i64 - outside_week;
+        if self.leap_week.is_some() {
+            // Every year starts on the first weekday
+            Ok(days_in_week.rem_euclid(weekdays) as usize)
+        } else {
+            let (days, outside_week) = self.counts_before_year(year);
+            let position = days - outside_week + days_in_week;
+            Ok((position + self.epoch_weekday as i64).rem_euclid(weekdays) as usize)
+        }
+    }
+    
+    /// Count the days outside the week in a year before `day_of_year`, and get the
+    /// intercalary day at `day_of_year` if it is outside the week
+    fn outside_week_in_year(&self, year: i32, day_of_year: u32) -> (i64, Option<&IntercalaryDay>) {
+        let rules = self.year_rules(year);
+        let mut outside_week = 0;
+        let mut day_start = 0;
+        for month in 0..=self.months.len() as u32 {
+            if month > 0 {
+                day_start += self.month_length(month as usize - 1, rules);
+            }
+            for day in self.intercalary_days_after(month, rules.leap_year) {
+                if day_start > day_of_year {
+                    break;
+                }
+                if day_start == day_of_year && day.outside_week {
+                    return (outside_week, Some(day));
+                }
+                if day.outside_week {
+                    outside_week += 1;
+                }
+                day_start += 1;
+            }
+        }
+        (outside_week, None)
+    }
+    
+    /// Split a day count since the epoch into a year and a 0-based day of that year
+    fn year_and_day(&self, total_days: i64) -> (i32, u32) {
+        let start_year = self.epoch.start_year;
+        let (year, day) = self.with_year_table(|table| {
+            if total_days >= 0 {
+                let (years, day) = table.after_epoch.split(self, total_days);
+                (start_year + years, day)
+            } else {
+                // Find the year before the epoch that the day falls in, counting back from its end
+                let (years, days_after) = table.before_epoch.split(self, -total_days - 1);
+                let length = table.before_epoch.year(self, years).0;
+                (start_year - 1 - years, length - 1 - days_after)
+            }
+        });
+        (clamp_year(year), day as u32)
+    }
+    
+    /// Check that the calendar is valid, returning the first problem found
+    ///
+    /// Calendars made with [`CustomCalendarBuilder::build`] are always valid; call this for
+    /// calendars loaded with serde, so that mistakes in the file are reported at load time
+    /// instead of showing up as wrong dates or panics later on.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ingame_clock::{CalendarError, CustomCalendar};
+    /// let calendar: CustomCalendar = ron::from_str(r##"(
+    ///     minutes_per_hour: 60,
+    ///     hours_per_day: 24,
+    ///     months: [(name: "Frostmoon", days: 20, leap_days: 1)],
+    ///     weekdays: ["Moonday"],
+    ///     leap_years: "# % 4 = 0",
+    ///     epoch: (name: "Age of Magic", start_year: 1000),
+    /// )"##).unwrap();
+    /// assert!(matches!(calendar.validate(), Err(CalendarError::InvalidExpression { field: "leap_years", .. })));
+    /// ```
+    pub fn validate(&self) -> Result<(), CalendarError> {
+        if self.months.is_empty() {
+            return Err(CalendarError::NoMonths);
+        }
+        if self.weekdays.is_empty() {
+            return Err(CalendarError::NoWeekdays);
+        }
+        for (field, value) in [
+            ("seconds_per_minute", self.seconds_per_minute),
+            ("minutes_per_hour", self.minutes_per_hour),
+            ("hours_per_day", self.hours_per_day),
+        ] {
+            if value == 0 {
+                return Err(CalendarError::ZeroTimeUnit { field });
+            }
+        }
+        if let Some((index, month)) = self.months.iter().enumerate().find(|(_, month)| month.days + month.leap_days == 0) {
+            return Err(CalendarError::EmptyMonth { month: index as u32 + 1, name: month.name.clone() });
+        }
+        if self.epoch_weekday >= self.weekdays.len() {
+            return Err(CalendarError::InvalidEpochWeekday { index: self.epoch_weekday });
+        }
+        
+        let month_count = self.months.len() as u32;
+        let months = self
+            .leap_week
+            .iter()
+            .map(|leap_week| ("leap_week", leap_week.month, 1))
+            .chain(self.intercalary_days.iter().map(|day| ("intercalary_days", day.after_month, 0)))
+            .chain(self.seasons.iter().map(|season| ("seasons", season.start_month, 1)));
+        for (field, month, first_month) in months {
+            if month < first_month || month > month_count {
+                return Err(CalendarError::UnknownMonth { field, month });
+            }
+        }
+        let common_year = YearRules { leap_year: false, leap_months: false, leap_week: false };
+        if self.period_lengths(common_year).sum::<u32>() == 0 {
+            return Err(CalendarError::EmptyYear);
+        }
+        
+        // Expressions are checked on the first year of the epoch
+        let year = clamp_year(self.epoch.start_year);
+        let rules = self.leap_rules();
+        let expressions = std::iter::once(("leap_years", &rules.leap_years))
+            .chain(rules.leap_months.iter().map(|expression| ("leap_months", expression)))
+            .chain(rules.leap_week.iter().map(|expression| ("leap_week", expression)));
+        for (field, expression) in expressions {
+            if let Err(message) = expression.evaluate(year) {
+                return Err(CalendarError::InvalidExpression {
+                    field,
+                    expression: expression.source.clone(),
+                    message,
+                });
+            }
+        }
+        
+        let starts: Vec<f64> = self.eras.iter().map(|era| self.era_start(era).0).collect();
+        if let Some(index) = (1..starts.len()).find(|&index| starts[index] < starts[index - 1]) {
+            return Err(CalendarError::UnorderedEra { index, name: self.eras[index].name.clone() });
+        }
+        Ok(())
+    }
+    
+    /// Check if a given year is a leap year according to this calendar's leap year expression
+    ///
+    /// Years for which the expression fails to evaluate are not leap years; see
+    /// [`CustomCalendar::validate`] to catch such expressions.
+    pub fn is_leap_year(&self, year: i32) -> bool {
+        self.leap_rules().leap_years.matches(year)
+    }
+    
+    /// Get the era at the given elapsed seconds since the epoch, with the year counted in that era
+    ///
+    /// Times before the start of the first era belong to the first era. Returns `None` if
+    /// the calendar has no eras.
+    pub fn era_at(&self, elapsed_seconds: f64) -> Option<(&Era, i32)> {
+        let starts: Vec<(f64, Option<i32>)> = self.eras.iter().map(|era| self.era_start(era)).collect();
+        let index = starts.partition_point(|(start, _)| *start <= elapsed_seconds).saturating_sub(1);
+        let era = self.eras.get(index)?;
+        
+        let total_days = (elapsed_seconds / self.seconds_per_day() as f64).floor() as i64;
+        let (year, _) = self.year_and_day(total_days);
+        let era_year = match era.direction {
+            EraDirection::Forward => starts[index].1.map_or(year as i64, |start_year| year as i64 - start_year as i64 + 1),
+            EraDirection::Backward => {
+                let end_year = starts.get(index + 1).and_then(|(_, start_year)| *start_year).or(starts[index].1);
+                end_year.unwrap_or_else(|| clamp_year(self.epoch.start_year)) as i64 - year as i64
+            }
+        };
+        Some((era, clamp_year(era_year)))
+    }
+    
+    /// Get the elapsed seconds at which an era starts, and the year it starts in
+    fn era_start(&self, era: &Era) -> (f64, Option<i32>) {
+        let seconds_per_day = self.seconds_per_day() as f64;
+        match era.start {
+            EraStart::Beginning => (f64::NEG_INFINITY, None),
+            EraStart::Year(year) => (self.days_before_year(year) as f64 * seconds_per_day, Some(year)),
+            EraStart::Elapsed(elapsed_seconds) => {
+                let (year, _) = self.year_and_day((elapsed_seconds / seconds_per_day).floor() as i64);
+                (elapsed_seconds, Some(year))
+            }
+        }
+    }
+    
+    /// Replace the date tokens of a custom format string
+    fn format_date_tokens(&self, format: &str, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> String {
+        let (year, month, day) = self.get_date(elapsed_seconds, start_datetime);
+        let (_, weekday) = self.get_weekday(elapsed_seconds, start_datetime);
+        let (era, era_year) = self
+            .era_at(elapsed_seconds)
+            .map_or((self.epoch.name.as_str(), year), |(era, era_year)| (era.name.as_str(), era_year));
+        
+        format
+            .replace("%EY", &format!("{} {}", era_year, era))
+            .replace("%Ey", &era_year.to_string())
+            .replace("%Y", &year.to_string())
+            .replace("%m", &format!("{:02}", month))
+            .replace("%d", &format!("{:02}", day))
+            .replace("%B", &self.period_name((year, month, day)))
+            .replace("%E", era)
+            .replace("%A", &weekday)
+    }
+    
+    /// Check if a given year has the leap week of the calendar's `leap_week` rule
+    pub fn has_leap_week(&self, year: i32) -> bool {
+        self.leap_rules().leap_week.as_ref().is_some_and(|expression| expression.matches(year))
+    }
+    
+    /// Check if the months marked `leap_month` exist in a given year, according to the
+    /// `leap_months` expression or, without one, the leap years
+    pub fn has_leap_months(&self, year: i32) -> bool {
+        match &self.leap_rules().leap_months {
+            Some(expression) => expression.matches(year),
+            None => self.is_leap_year(year),
+        }
+    }
+}
+
+impl Calendar for CustomCalendar {
+    fn seconds_per_minute(&self) -> u32 {
+        self.seconds_per_minute
+    }
+    
+    fn seconds_per_day(&self) -> u32 {
+        self.seconds_per_hour() * self.hours_per_day
+    }
+    
+    fn seconds_per_hour(&self) -> u32 {
+        self.seconds_per_minute() * self.minutes_per_hour
+    }
+    
+    fn seconds_per_week(&self) -> u32 {
+        self.seconds_per_day() * self.weekdays.len() as u32
+    }
+    
+    fn day_start(&self, elapsed_seconds: f64, _start_datetime: NaiveDateTime) -> f64 {
+        let seconds_per_day = self.seconds_per_day() as f64;
+        (elapsed_seconds / seconds_per_day).floor() * seconds_per_day
     }
     
-    /// Check if a given year is a leap year according to this calendar's leap year expression
-    pub fn is_leap_year(&self, year: i32) -> bool {
-        // Replace # placeholder with the actual year value
-        let expression = self.leap_years.replace("#", &year.to_string());
+    fn week_start(&self, elapsed_seconds: f64, _start_datetime: NaiveDateTime) -> f64 {
+        let seconds_per_day = self.seconds_per_day() as f64;
+        let mut day = (elapsed_seconds / seconds_per_day).floor() as i64;
+        if self.leap_week.is_none() && !self.has_days_outside_week() {
+            let weekday = self.weekday_of_day(day).unwrap_or_default() as i64;
+            return (day - weekday) as f64 * seconds_per_day;
+        }
         
-        // Evaluate the expression
-        eval_boolean(&expression)
-            .unwrap_or(false)
+        // Walk back over days outside the week and short weeks at the end of a year
+        while self.weekday_of_day(day) != Ok(0) {
+            day -= 1;
+        }
+        day as f64 * seconds_per_day
     }
     
-    fn seconds_per_minute(&self) -> u32 {
-        60 // Keep seconds at 60 for consistency
+    fn year_start(&self, elapsed_seconds: f64, _start_datetime: NaiveDateTime) -> f64 {
+        let total_days = (elapsed_seconds / self.seconds_per_day() as f64).floor() as i64;
+        let (_, day_of_year) = self.year_and_day(total_days);
+        (total_days - day_of_year as i64) as f64 * self.seconds_per_day() as f64
+    }
+    
+    fn month_names(&self) -> Vec<String> {
+        self.months.iter().map(|month| month.name.clone()).collect()
+    }
+    
+    fn max_days_in_month(&self) -> u32 {
+        let rules = YearRules {
+            leap_year: true,
+            leap_months: true,
+            leap_week: true,
+        };
+        (0..self.months.len()).map(|index| self.month_length(index, rules)).max().unwrap_or(0)
+    }
+    
+    fn weekday_names(&self) -> Vec<String> {
+        self.weekdays.clone()
+    }
+    
+    fn weekday_count(&self) -> usize {
+        self.weekdays.len()
     }
     
-    /// Get the weekday name for the current elapsed time
-    fn get_weekday(&self, elapsed_seconds: f64) -> String {
+    /// Days outside the week return `weekday_count()` as their index, with their own name
+    fn get_weekday(&self, elapsed_seconds: f64, _start_datetime: NaiveDateTime) -> (usize, String) {
+        // The first day of the epoch is `epoch_weekday`, and days before it count backwards
         let total_days = (elapsed_seconds / self.seconds_per_day() as f64).floor() as i64;
-        let weekday_index = (total_days % self.weekdays.len() as i64) as usize;
-        self.weekdays[weekday_index].clone()
+        match self.weekday_of_day(total_days) {
+            Ok(index) => (index, self.weekdays[index].clone()),
+            Err(day) => (self.weekdays.len(), day.name.clone()),
+        }
     }
-}
-
-impl Calendar for CustomCalendar {
-    fn seconds_per_day(&self) -> u32 {
-        self.seconds_per_hour() * self.hours_per_day
+    
+    fn intercalary_day_name(&self, date: (i32, u32, u32)) -> Option<String> {
+        self.intercalary_day(date).map(|day| day.name.clone())
     }
     
-    fn seconds_per_hour(&self) -> u32 {
-        self.seconds_per_minute() * self.minutes_per_hour
+    fn get_era(&self, elapsed_seconds: f64, _start_datetime: NaiveDateTime) -> Option<(String, i32)> {
+        self.era_at(elapsed_seconds).map(|(era, year)| (era.name.clone(), year))
     }
     
-    fn seconds_per_week(&self) -> u32 {
-        self.seconds_per_day() * self.weekdays.len() as u32
+    fn seasons(&self) -> Vec<Season> {
+        self.seasons.clone()
+    }
+    
+    fn definition(&self) -> Option<CalendarDefinition> {
+        Some(CalendarDefinition::Custom(Box::new(self.clone())))
     }
     
     fn get_date(&self, elapsed_seconds: f64, _start_datetime: NaiveDateTime) -> (i32, u32, u32) {
         let total_days = (elapsed_seconds / self.seconds_per_day() as f64).floor() as i64;
-        let days_per_year = self.days_per_year() as i64;
-        
-        let years_since_epoch = total_days / days_per_year;
-        let year = self.epoch.start_year + years_since_epoch;
-        let day_of_year = (total_days % days_per_year) as u32;
-        let is_leap_year = self.is_leap_year(year as i32);
-        // Find which month and day within that month
+        let (year, day_of_year) = self.year_and_day(total_days);
+        // Find which month and day within that month, counting intercalary days
+        // under the month they follow
         let mut days_remaining = day_of_year;
         let mut month = 1u32;
         
-        for (idx, month_def) in self.months.iter().enumerate() {
-            if is_leap_year {
-                if days_remaining < month_def.days + month_def.leap_days {
-                    month = (idx + 1) as u32;
-                    break;
-                }
-                days_remaining -= month_def.days + month_def.leap_days;
-            } else {
-                if days_remaining < month_def.days {
-                    month = (idx + 1) as u32;
-                    break;
-                }
-                days_remaining -= month_def.days;
+        for (idx, period_length) in self.period_lengths(self.year_rules(year)).enumerate() {
+            if days_remaining < period_length {
+                month = idx as u32;
+                break;
             }
+            days_remaining -= period_length;
         }
         
         let day = days_remaining + 1; // 1-indexed
         
-        (year as i32, month, day)
+        (year, month, day)
+    }
+    
+    fn to_elapsed(&self, date: (i32, u32, u32), time: (u32, u32, u32), _start_datetime: NaiveDateTime) -> Result<f64, DateError> {
+        let (year, month, day) = date;
+        let (hour, minute, second) = time;
+        if day == 0 || day > self.period_lengths(self.year_rules(year)).nth(month as usize).unwrap_or(0) {
+            return Err(DateError::InvalidDate { year, month, day });
+        }
+        if hour >= self.hours_per_day || minute >= self.minutes_per_hour || second >= self.seconds_per_minute() {
+            return Err(DateError::InvalidTime { hour, minute, second });
+        }
+        
+        let days_before_month: i64 = self.period_lengths(self.year_rules(year)).take(month as usize).map(i64::from).sum();
+        let days = self.days_before_year(year) + days_before_month + day as i64 - 1;
+        let seconds = hour as u64 * self.seconds_per_hour() as u64 + minute as u64 * self.seconds_per_minute() as u64 + second as u64;
+        Ok(days as f64 * self.seconds_per_day() as f64 + seconds as f64)
     }
     
     fn get_time(&self, elapsed_seconds: f64, _start_datetime: NaiveDateTime) -> (u32, u32, u32) {
         let seconds_per_day = self.seconds_per_day() as f64;
-        let seconds_today = elapsed_seconds % seconds_per_day;
+        // Times before the epoch still count forward from the start of their day
+        let seconds_today = elapsed_seconds.rem_euclid(seconds_per_day);
         
         let seconds_per_hour = self.seconds_per_hour() as f64;
         let seconds_per_minute = self.seconds_per_minute() as f64;
@@ -442,18 +2099,11 @@ impl Calendar for CustomCalendar {
     }
     
     fn format_date(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime, format: Option<&str>) -> String {
-        let (year, month, day) = self.get_date(elapsed_seconds, start_datetime);
-        let weekday = self.get_weekday(elapsed_seconds);
-        
         if let Some(fmt) = format {
             // Simple custom format support
-            fmt.replace("%Y", &year.to_string())
-                .replace("%m", &format!("{:02}", month))
-                .replace("%d", &format!("{:02}", day))
-                .replace("%B", &self.months[(month - 1) as usize].name)
-                .replace("%E", &self.epoch.name)
-                .replace("%A", &weekday)
+            self.format_date_tokens(fmt, elapsed_seconds, start_datetime)
         } else {
+            let (year, month, day) = self.get_date(elapsed_seconds, start_datetime);
             format!("{:04}-{:02}-{:02}", year, month, day)
         }
     }
@@ -475,16 +2125,9 @@ impl Calendar for CustomCalendar {
         let time = self.format_time(elapsed_seconds, start_datetime, None);
         
         if let Some(fmt) = format {
-            let (year, month, day) = self.get_date(elapsed_seconds, start_datetime);
             let (hour, minute, second) = self.get_time(elapsed_seconds, start_datetime);
-            let weekday = self.get_weekday(elapsed_seconds);
             
-            fmt.replace("%Y", &year.to_string())
-                .replace("%m", &format!("{:02}", month))
-                .replace("%d", &format!("{:02}", day))
-                .replace("%B", &self.months[(month - 1) as usize].name)
-                .replace("%E", &self.epoch.name)
-                .replace("%A", &weekday)
+            self.format_date_tokens(fmt, elapsed_seconds, start_datetime)
                 .replace("%H", &format!("{:02}", hour))
                 .replace("%M", &format!("{:02}", minute))
                 .replace("%S", &format!("{:02}", second))
@@ -611,6 +2254,180 @@ mod tests {
         assert_eq!(day, 1);
     }
     
+    #[test]
+    fn test_leap_years_do_not_drift() {
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(8)
+            .month(Month::new("Frostmoon", 20, 3))
+            .month(Month::new("Thawmoon", 21, 0))
+            .month(Month::new("Bloomtide", 19, 2))
+            .weekday("Moonday")
+            .leap_years("# % 2 == 0")
+            .epoch(Epoch::new("Age of Magic", 1000))
+            .build();
+        let start = chrono::NaiveDateTime::default();
+        let date = |days: i64| calendar.get_date(days as f64 * calendar.seconds_per_day() as f64, start);
+        assert_eq!(calendar.days_in_year(1000), 65);
+        assert_eq!(calendar.days_in_year(1001), 60);
+        assert_eq!(calendar.days_in_month(1001, 1), 20);
+        
+        // Year 1000 is a leap year of 65 days, followed by a common year of 60 days
+        assert_eq!(date(64), (1000, 3, 21));
+        assert_eq!(date(65), (1001, 1, 1));
+        assert_eq!(date(125), (1002, 1, 1));
+        
+        // 49500 leap and 49500 common years pass before year 100000
+        let days = 49500 * 65 + 49500 * 60;
+        assert_eq!(date(days), (100000, 1, 1));
+        assert_eq!(date(days - 1), (99999, 3, 19));
+        assert_eq!(date(-1), (999, 3, 19));
+    }
+    
+    #[test]
+    fn test_leap_rule_reform() {
+        // Leap years stop after a calendar reform in year 3000
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Frostmoon", 30, 1))
+            .weekday("Moonday")
+            .leap_years("# % 4 == 0 && # < 3000")
+            .epoch(Epoch::new("Age of Magic", 0))
+            .build();
+        let start = chrono::NaiveDateTime::default();
+        let date = |days: i64| calendar.get_date(days as f64 * calendar.seconds_per_day() as f64, start);
+        
+        // 750 leap years pass before the reform, none after it
+        let days = 3100 * 30 + 750;
+        assert_eq!(date(days), (3100, 1, 1));
+        assert_eq!(date(days - 1), (3099, 1, 30));
+        assert_eq!(calendar.to_elapsed((3100, 1, 1), (0, 0, 0), start).unwrap(), days as f64 * 86400.0);
+        assert_eq!(date(-1), (-1, 1, 30));
+        assert_eq!(date(-121), (-4, 1, 1));
+        
+        // A single leap year far from the epoch
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Frostmoon", 30, 1))
+            .weekday("Moonday")
+            .leap_years("# == 5000")
+            .epoch(Epoch::new("Age of Magic", 0))
+            .build();
+        assert_eq!(calendar.get_date(150_030.0 * 86400.0, start), (5000, 1, 31));
+        assert_eq!(calendar.get_date(150_031.0 * 86400.0, start), (5001, 1, 1));
+    }
+    
+    #[test]
+    fn test_leap_years_match_gregorian() {
+        let months = [("Jan", 31, 0), ("Feb", 28, 1), ("Mar", 31, 0), ("Apr", 30, 0), ("May", 31, 0), ("Jun", 30, 0),
+            ("Jul", 31, 0), ("Aug", 31, 0), ("Sep", 30, 0), ("Oct", 31, 0), ("Nov", 30, 0), ("Dec", 31, 0)];
+        let calendar = CustomCalendar::builder()
+            .months(months.iter().map(|(name, days, leap_days)| Month::new(*name, *days, *leap_days)).collect())
+            .weekday("Day")
+            .leap_years("# % 4 == 0 && (# % 100 != 0 || # % 400 == 0)")
+            .epoch(Epoch::new("CE", 1970))
+            .build();
+        let start = chrono::NaiveDateTime::default();
+        let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        
+        for (year, month, day) in [(2000, 2, 29), (2100, 3, 1), (2400, 2, 29), (12345, 12, 31), (1900, 2, 28)] {
+            let days = (chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap() - epoch).num_days();
+            assert_eq!(calendar.get_date(days as f64 * 86400.0, start), (year, month, day));
+        }
+    }
+    
+    /// Calendar relying on the default [`Calendar::to_elapsed`] search
+    struct SearchedCalendar(CustomCalendar);
+    
+    impl Calendar for SearchedCalendar {
+        fn format_date(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime, format: Option<&str>) -> String {
+            self.0.format_date(elapsed_seconds, start_datetime, format)
+        }
+        
+        fn format_time(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime, format: Option<&str>) -> String {
+            self.0.format_time(elapsed_seconds, start_datetime, format)
+        }
+        
+        fn format_datetime(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime, format: Option<&str>) -> String {
+            self.0.format_datetime(elapsed_seconds, start_datetime, format)
+        }
+        
+        fn get_date(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> (i32, u32, u32) {
+            self.0.get_date(elapsed_seconds, start_datetime)
+        }
+        
+        fn get_time(&self, elapsed_seconds: f64, start_datetime: NaiveDateTime) -> (u32, u32, u32) {
+            self.0.get_time(elapsed_seconds, start_datetime)
+        }
+        
+        fn seconds_per_day(&self) -> u32 {
+            self.0.seconds_per_day()
+        }
+        
+        fn seconds_per_hour(&self) -> u32 {
+            self.0.seconds_per_hour()
+        }
+    }
+    
+    #[test]
+    fn test_to_elapsed_round_trip() {
+        let calendar = CustomCalendar::builder()
+            .minutes_per_hour(20)
+            .hours_per_day(8)
+            .month(Month::new("Frostmoon", 20, 3))
+            .month(Month::new("Thawmoon", 21, 0))
+            .weekday("Moonday")
+            .leap_years("# % 3 == 0")
+            .epoch(Epoch::new("Age of Magic", 1000))
+            .build();
+        let searched = SearchedCalendar(calendar.clone());
+        let start = NaiveDateTime::default();
+        
+        for elapsed in [0.0, 9599.0, 9600.0, 1234567.0, 3.0e10] {
+            let date = calendar.get_date(elapsed, start);
+            let time = calendar.get_time(elapsed, start);
+            assert_eq!(calendar.to_elapsed(date, time, start), Ok(elapsed));
+            assert_eq!(searched.to_elapsed(date, time, start), Ok(elapsed));
+        }
+        
+        // Year 1001 is a common year with 20 days in Frostmoon, unlike leap year 1002
+        assert_eq!(calendar.to_elapsed((1001, 1, 21), (0, 0, 0), start), Err(DateError::InvalidDate { year: 1001, month: 1, day: 21 }));
+        assert_eq!(calendar.to_elapsed((1002, 1, 23), (0, 0, 0), start), calendar.to_elapsed((1002, 2, 1), (0, 0, 0), start).map(|e| e - 9600.0));
+        assert!(calendar.to_elapsed((1001, 2, 22), (0, 0, 0), start).is_err());
+        assert!(calendar.to_elapsed((1001, 3, 1), (0, 0, 0), start).is_err());
+        assert!(calendar.to_elapsed((1001, 1, 1), (0, 20, 0), start).is_err());
+        assert!(searched.to_elapsed((1001, 1, 21), (0, 0, 0), start).is_err());
+        assert!(searched.to_elapsed((1001, 1, 1), (8, 0, 0), start).is_err());
+        
+        // Years before the epoch count backwards
+        assert_eq!(calendar.to_elapsed((999, 2, 21), (7, 19, 59), start), Ok(-1.0));
+    }
+    
+    #[test]
+    fn test_gregorian_to_elapsed() {
+        let start = NaiveDateTime::new(
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            chrono::NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+        );
+        assert_eq!(GregorianCalendar.to_elapsed((2024, 1, 2), (12, 0, 1), start), Ok(86401.0));
+        assert_eq!(GregorianCalendar.to_elapsed((2024, 1, 1), (0, 0, 0), start), Ok(-43200.0));
+        assert!(GregorianCalendar.to_elapsed((2023, 2, 29), (0, 0, 0), start).is_err());
+        assert!(GregorianCalendar.to_elapsed((2024, 1, 1), (24, 0, 0), start).is_err());
+    }
+    
+    #[test]
+    fn test_non_periodic_leap_years() {
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Month1", 10, 1))
+            .weekday("Day")
+            .leap_years("# >= 5")
+            .epoch(Epoch::new("Test Epoch", 0))
+            .build();
+        let start = chrono::NaiveDateTime::default();
+        
+        // Five common years of 10 days, then leap years of 11 days
+        assert_eq!(calendar.get_date(50.0 * 86400.0, start), (5, 1, 1));
+        assert_eq!(calendar.get_date(61.0 * 86400.0, start), (6, 1, 1));
+        assert_eq!(calendar.get_date(-86400.0, start), (-1, 1, 10));
+    }
+    
     #[test]
     fn test_expression_based_leap_year_gregorian() {
         let calendar = CustomCalendar::builder()
@@ -680,9 +2497,51 @@ mod tests {
         assert!(calendar.is_leap_year(54));  // Divisible by 27
     }
     
+    #[test]
+    fn test_leap_expressions_are_compiled_once() {
+        let start_datetime = chrono::NaiveDateTime::default();
+        let parsed = || PARSED_EXPRESSIONS.with(|count| count.get());
+        let before = parsed();
+        let mut calendar = CustomCalendar::builder()
+            .month(Month::new("Month1", 30, 1))
+            .weekday("Day1")
+            .leap_years("# % 4 == 0 && (year % 100 != 0 || # % 400 == 0)")
+            .epoch(Epoch::new("CE", 1))
+            .build();
+        
+        // Building and validating the calendar parses the expression once
+        assert_eq!(parsed(), before + 1);
+        
+        // Formatting evaluates the compiled expression without parsing it again
+        for day in 0..10_000 {
+            let elapsed = day as f64 * 86400.0 * 47.0;
+            calendar.format_date(elapsed, start_datetime, Some("%d %B %Y"));
+            calendar.format_datetime(-elapsed, start_datetime, None);
+        }
+        assert_eq!(parsed(), before + 1);
+        assert!(calendar.is_leap_year(2000));
+        assert!(!calendar.is_leap_year(2100));
+        assert!(calendar.is_leap_year(-4));
+        
+        // Deserializing compiles the expression once as well
+        let serialized = ron::to_string(&calendar).unwrap();
+        let deserialized: CustomCalendar = ron::from_str(&serialized).unwrap();
+        assert_eq!(parsed(), before + 2);
+        deserialized.format_date(86400.0 * 365.0 * 2000.0, start_datetime, None);
+        assert!(deserialized.validate().is_ok());
+        assert_eq!(parsed(), before + 2);
+        
+        // Changed expressions are compiled again once, on their next use
+        calendar.leap_years = "# % 4 == 0".to_string();
+        assert!(calendar.is_leap_year(2100));
+        assert_eq!(calendar.days_in_year(2100), 31);
+        assert_eq!(calendar.get_date(86400.0 * 121.0, start_datetime), (5, 1, 1));
+        assert_eq!(parsed(), before + 3);
+    }
+    
     #[test]
     fn test_expression_invalid_returns_false() {
-        let calendar = CustomCalendar::builder()
+        let builder = CustomCalendar::builder()
             .minutes_per_hour(60)
             .hours_per_day(24)
                         .month(Month::new("Month1", 30, 1))
@@ -690,11 +2549,13 @@ mod tests {
                 "Mon".to_string(), "Tue".to_string(), "Wed".to_string(),
                 "Thu".to_string(), "Fri".to_string(), "Sat".to_string(), "Sun".to_string()
             ])
-            .leap_years("invalid expression here")
-            .epoch(Epoch::new("Test Epoch", 0))
-            .build();
+            .epoch(Epoch::new("Test Epoch", 0));
+        assert!(builder.clone().leap_years("invalid expression here").try_build().is_err());
         
-        // Should return false for invalid expression
+        // Calendars that skipped validation still work, without leap years
+        let mut calendar = builder.build();
+        calendar.leap_years = "invalid expression here".to_string();
+        assert!(calendar.validate().is_err());
         assert!(!calendar.is_leap_year(2000));
         assert!(!calendar.is_leap_year(2004));
     }
@@ -826,6 +2687,400 @@ mod tests {
         assert_eq!(calendar.leap_years, "false");
     }
     
+    #[test]
+    fn test_season_lookup() {
+        let seasons = GregorianCalendar.seasons();
+        assert_eq!(Season::find(&seasons, 3), Some((0, 0)));
+        assert_eq!(Season::find(&seasons, 8), Some((1, 0)));
+        assert_eq!(Season::find(&seasons, 12), Some((3, 0)));
+        // January and February belong to the winter that started the previous year
+        assert_eq!(Season::find(&seasons, 1), Some((3, -1)));
+        assert_eq!(Season::find(&[], 1), None);
+    }
+    
+    #[test]
+    fn test_day_start() {
+        let start_datetime = chrono::NaiveDateTime::new(
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            chrono::NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+        );
+        // Gregorian days start at midnight, regardless of the clock's start time
+        assert_eq!(GregorianCalendar.day_start(0.0, start_datetime), -8.5 * 3600.0);
+        assert_eq!(GregorianCalendar.day_start(20.0 * 3600.0, start_datetime), 15.5 * 3600.0);
+        
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(20)
+            .month(Month::new("Month1", 30, 0))
+            .weekday("Day1")
+            .build();
+        assert_eq!(calendar.day_start(72000.0 * 2.5, start_datetime), 144000.0);
+    }
+    
+    #[test]
+    fn test_week_start() {
+        // 2024-01-03 is a Wednesday
+        let start_datetime = chrono::NaiveDateTime::new(
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
+            chrono::NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+        );
+        let monday = -(2.0 * 86400.0 + 8.5 * 3600.0);
+        assert_eq!(GregorianCalendar.week_start(0.0, start_datetime), monday);
+        assert_eq!(GregorianCalendar.week_start(4.0 * 86400.0, start_datetime), monday);
+        assert_eq!(GregorianCalendar.week_start(5.0 * 86400.0, start_datetime), monday + 604800.0);
+        
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Month1", 30, 0))
+            .weekdays(vec!["Day1".to_string(), "Day2".to_string(), "Day3".to_string()])
+            .build();
+        assert_eq!(calendar.week_start(86400.0 * 4.5, start_datetime), 86400.0 * 3.0);
+    }
+    
+    #[test]
+    fn test_custom_seconds_per_minute() {
+        let start_datetime = chrono::NaiveDateTime::default();
+        let calendar = CustomCalendar::builder()
+            .seconds_per_minute(100)
+            .minutes_per_hour(10)
+            .hours_per_day(10)
+            .month(Month::new("Month1", 30, 0))
+            .weekday("Day1")
+            .build();
+        assert_eq!(calendar.seconds_per_hour(), 1000);
+        assert_eq!(calendar.seconds_per_day(), 10000);
+        
+        let elapsed = 10000.0 + 3.0 * 1000.0 + 4.0 * 100.0 + 75.0;
+        assert_eq!(calendar.get_time(elapsed, start_datetime), (3, 4, 75));
+        assert_eq!(calendar.format_time(elapsed, start_datetime, None), "03:04:75");
+        assert_eq!(calendar.day_start(elapsed, start_datetime), 10000.0);
+        assert_eq!(calendar.to_elapsed((1, 1, 2), (3, 4, 75), start_datetime), Ok(elapsed));
+        assert!(calendar.to_elapsed((1, 1, 2), (3, 4, 100), start_datetime).is_err());
+        
+        // Calendars saved before the field existed keep 60-second minutes
+        let mut serialized = ron::to_string(&calendar).unwrap();
+        serialized = serialized.replace("seconds_per_minute:100,", "");
+        let deserialized: CustomCalendar = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.seconds_per_minute, 60);
+        assert_eq!(deserialized.seconds_per_hour(), 600);
+    }
+    
+    #[test]
+    fn test_french_republican() {
+        let start_datetime = chrono::NaiveDateTime::default();
+        let calendar = CustomCalendar::french_republican();
+        assert_eq!(calendar.seconds_per_day(), 100000);
+        assert_eq!(calendar.seconds_per_week(), 1000000);
+        
+        // Years 3, 7, 11 and 15 are leap years, then the Gregorian rule from year 20
+        let leap_years: Vec<i32> = (1..=30).filter(|year| calendar.is_leap_year(*year)).collect();
+        assert_eq!(leap_years, vec![3, 7, 11, 15, 20, 24, 28]);
+        assert!(!calendar.is_leap_year(100));
+        assert!(calendar.is_leap_year(400));
+        assert_eq!(calendar.days_in_year(2), 365);
+        assert_eq!(calendar.days_in_year(3), 366);
+        
+        // The complementary days follow Fructidor
+        let day = |year: i32| calendar.to_elapsed((year, 1, 1), (0, 0, 0), start_datetime).unwrap();
+        let vertu = day(2) + 360.0 * 100000.0 + 12345.0;
+        assert_eq!(calendar.get_date(vertu, start_datetime), (2, 12, 31));
+        assert_eq!(calendar.get_time(vertu, start_datetime), (1, 23, 45));
+        assert_eq!(calendar.format_datetime(vertu, start_datetime, Some("%B %Y %H:%M:%S")), "Jour de la vertu 2 01:23:45");
+        assert_eq!(calendar.format_date(vertu - 100000.0, start_datetime, Some("%d %B")), "30 Fructidor");
+        assert_eq!(calendar.get_date(day(2) + 365.0 * 100000.0, start_datetime), (3, 1, 1));
+        
+        // The sixth complementary day only exists in leap years
+        let revolution = calendar.to_elapsed((3, 12, 36), (0, 0, 0), start_datetime).unwrap();
+        assert_eq!(calendar.format_date(revolution, start_datetime, Some("%B")), "Jour de la Révolution");
+        assert_eq!(calendar.get_date(revolution + 100000.0, start_datetime), (4, 1, 1));
+        assert!(calendar.to_elapsed((2, 12, 36), (0, 0, 0), start_datetime).is_err());
+        assert!(calendar.intercalary_day((2, 12, 36)).is_none());
+        
+        // Every month starts a new décade
+        assert_eq!(calendar.get_weekday(day(3), start_datetime), (0, "Primidi".to_string()));
+        assert_eq!(calendar.get_weekday(vertu, start_datetime), (10, "Jour de la vertu".to_string()));
+    }
+    
+    fn harptos_calendar() -> CustomCalendar {
+        let months = [
+            "Hammer", "Alturiak", "Ches", "Tarsakh", "Mirtul", "Kythorn",
+            "Flamerule", "Eleasis", "Eleint", "Marpenoth", "Uktar", "Nightal",
+        ];
+        CustomCalendar::builder()
+            .months(months.iter().map(|name| Month::new(*name, 30, 0)).collect())
+            .weekdays((1..=10).map(|day| format!("Day{}", day)).collect())
+            .intercalary_day(IntercalaryDay::new("Midwinter", 1, false).outside_week())
+            .intercalary_day(IntercalaryDay::new("Greengrass", 4, false).outside_week())
+            .intercalary_day(IntercalaryDay::new("Midsummer", 7, false).outside_week())
+            .intercalary_day(IntercalaryDay::new("Shieldmeet", 7, true).outside_week())
+            .intercalary_day(IntercalaryDay::new("Highharvestide", 9, false).outside_week())
+            .intercalary_day(IntercalaryDay::new("Feast of the Moon", 11, false).outside_week())
+            .leap_years("# % 4 == 0")
+            .epoch(Epoch::new("Dale Reckoning", 1))
+            .build()
+    }
+    
+    #[test]
+    fn test_intercalary_days_outside_week() {
+        let start_datetime = chrono::NaiveDateTime::default();
+        let calendar = harptos_calendar();
+        let elapsed = |date: (i32, u32, u32)| calendar.to_elapsed(date, (0, 0, 0), start_datetime).unwrap();
+        assert_eq!(calendar.days_in_year(1371), 365);
+        assert_eq!(calendar.days_in_year(1372), 366);
+        
+        // Festivals do not shift the tendays, so every month starts on the first weekday
+        for year in [-3, 1, 1371, 1372] {
+            for month in 1..=12 {
+                assert_eq!(calendar.get_weekday(elapsed((year, month, 1)), start_datetime), (0, "Day1".to_string()));
+            }
+        }
+        assert_eq!(calendar.get_date(elapsed((1372, 7, 32)), start_datetime), (1372, 7, 32));
+        assert_eq!(calendar.format_date(elapsed((1372, 7, 32)), start_datetime, Some("%B")), "Shieldmeet");
+        
+        // Festivals have no weekday of their own and extend the week before them
+        let midwinter = elapsed((1372, 1, 31));
+        assert_eq!(calendar.get_weekday(midwinter, start_datetime), (10, "Midwinter".to_string()));
+        assert_eq!(calendar.format_date(midwinter, start_datetime, Some("%A")), "Midwinter");
+        assert_eq!(calendar.week_start(midwinter, start_datetime), elapsed((1372, 1, 21)));
+        assert_eq!(calendar.week_start(elapsed((1372, 2, 1)), start_datetime), elapsed((1372, 2, 1)));
+        assert_eq!(calendar.week_start(elapsed((1372, 7, 32)) + 100.0, start_datetime), elapsed((1372, 7, 21)));
+    }
+    
+    #[test]
+    fn test_intercalary_days_before_first_month() {
+        let start_datetime = chrono::NaiveDateTime::default();
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Afteryule", 30, 0))
+            .month(Month::new("Foreyule", 30, 0))
+            .weekday("Sterday")
+            .intercalary_day(IntercalaryDay::new("1 Yule", 0, false))
+            .intercalary_day(IntercalaryDay::new("2 Yule", 2, false))
+            .build();
+        assert_eq!(calendar.days_in_year(1), 62);
+        assert_eq!(calendar.get_date(0.0, start_datetime), (1, 0, 1));
+        assert_eq!(calendar.format_date(0.0, start_datetime, Some("%B")), "1 Yule");
+        assert_eq!(calendar.get_date(86400.0, start_datetime), (1, 1, 1));
+        assert_eq!(calendar.get_date(61.0 * 86400.0, start_datetime), (1, 2, 31));
+        assert_eq!(calendar.get_date(62.0 * 86400.0, start_datetime), (2, 0, 1));
+        for date in [(1, 0, 1), (1, 1, 30), (1, 2, 31), (2, 0, 1)] {
+            let elapsed = calendar.to_elapsed(date, (12, 0, 0), start_datetime).unwrap();
+            assert_eq!(calendar.get_date(elapsed, start_datetime), date);
+        }
+        assert!(calendar.to_elapsed((1, 0, 2), (0, 0, 0), start_datetime).is_err());
+    }
+    
+    #[test]
+    fn test_leap_months() {
+        let start_datetime = chrono::NaiveDateTime::default();
+        let calendar = CustomCalendar::builder()
+            .months(vec![
+                Month::new("Tishrei", 30, 0),
+                Month::new("Heshvan", 29, 0),
+                Month::new("Kislev", 30, 0),
+                Month::new("Tevet", 29, 0),
+                Month::new("Shevat", 30, 0),
+                Month::new("Adar", 29, 0),
+                Month::leap_month("Adar II", 29),
+                Month::new("Nisan", 30, 0),
+                Month::new("Iyar", 29, 0),
+                Month::new("Sivan", 30, 0),
+                Month::new("Tammuz", 29, 0),
+                Month::new("Av", 30, 0),
+                Month::new("Elul", 29, 0),
+            ])
+            .weekday("Yom Rishon")
+            .leap_months("(7 * # + 1) % 19 < 7")
+            .epoch(Epoch::new("Anno Mundi", 5700))
+            .build();
+        
+        // Seven leap-month years in every 19-year Metonic cycle
+        assert!(calendar.has_leap_months(5784));
+        assert!(!calendar.has_leap_months(5785));
+        assert_eq!((5700..5719).filter(|year| calendar.has_leap_months(*year)).count(), 7);
+        assert!(!calendar.is_leap_year(5784));
+        assert_eq!(calendar.days_in_year(5784), 383);
+        assert_eq!(calendar.days_in_year(5785), 354);
+        assert_eq!(calendar.days_in_month(5784, 7), 29);
+        assert_eq!(calendar.days_in_month(5785, 7), 0);
+        
+        // The leap month keeps its number, and is skipped in other years
+        let adar_ii = calendar.to_elapsed((5784, 7, 1), (0, 0, 0), start_datetime).unwrap();
+        assert_eq!(calendar.get_date(adar_ii - 86400.0, start_datetime), (5784, 6, 29));
+        assert_eq!(calendar.format_date(adar_ii, start_datetime, Some("%d %B %Y")), "01 Adar II 5784");
+        assert_eq!(calendar.get_date(adar_ii + 29.0 * 86400.0, start_datetime), (5784, 8, 1));
+        let adar = calendar.to_elapsed((5785, 6, 29), (0, 0, 0), start_datetime).unwrap();
+        assert_eq!(calendar.get_date(adar + 86400.0, start_datetime), (5785, 8, 1));
+        assert!(calendar.to_elapsed((5785, 7, 1), (0, 0, 0), start_datetime).is_err());
+        
+        // Without a leap month rule, leap months follow the leap years
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Month1", 30, 0))
+            .month(Month::leap_month("Month2", 10))
+            .weekday("Day1")
+            .leap_years("# % 2 == 0")
+            .build();
+        assert_eq!(calendar.days_in_year(1), 30);
+        assert_eq!(calendar.days_in_year(2), 40);
+    }
+    
+    #[test]
+    fn test_leap_week() {
+        let start_datetime = chrono::NaiveDateTime::default();
+        let months = [
+            "January", "February", "March", "April", "May", "June",
+            "July", "August", "September", "October", "November", "December",
+        ];
+        let calendar = CustomCalendar::builder()
+            .months(months.iter().enumerate().map(|(i, name)| Month::new(*name, if i % 3 == 1 { 35 } else { 28 }, 0)).collect())
+            .weekdays(["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"].map(String::from).to_vec())
+            .leap_week(LeapWeek::new("(52 * # + 146) % 293 < 52", 12))
+            .epoch(Epoch::new("Common Era", 1))
+            .epoch_weekday(3)
+            .build();
+        
+        // Symmetry454 adds a week to December in 52 out of every 293 years
+        assert!(calendar.has_leap_week(2004));
+        assert_eq!(calendar.days_in_year(2004), 371);
+        assert_eq!(calendar.days_in_year(2005), 364);
+        assert_eq!(calendar.days_in_month(2004, 12), 35);
+        assert_eq!((2000..2293).filter(|year| calendar.has_leap_week(*year)).count(), 52);
+        
+        // Every year starts on the first weekday, whatever the epoch weekday
+        for year in 1990..2010 {
+            let new_year = calendar.to_elapsed((year, 1, 1), (12, 0, 0), start_datetime).unwrap();
+            assert_eq!(calendar.get_weekday(new_year, start_datetime), (0, "Monday".to_string()));
+        }
+        let leap_day = calendar.to_elapsed((2004, 12, 35), (12, 0, 0), start_datetime).unwrap();
+        assert_eq!(calendar.get_date(leap_day, start_datetime), (2004, 12, 35));
+        assert_eq!(calendar.get_weekday(leap_day, start_datetime).1, "Sunday");
+        assert_eq!(calendar.get_date(leap_day + 86400.0, start_datetime), (2005, 1, 1));
+        assert!(calendar.to_elapsed((2005, 12, 35), (0, 0, 0), start_datetime).is_err());
+        
+        // The leap week is a full week of its own
+        let week_start = calendar.week_start(leap_day, start_datetime);
+        assert_eq!(calendar.get_date(week_start, start_datetime), (2004, 12, 29));
+    }
+    
+    #[test]
+    fn test_eras() {
+        let start_datetime = chrono::NaiveDateTime::default();
+        let calendar = CustomCalendar::builder()
+            .months((1..=12).map(|month| Month::new(format!("Month{}", month), 30, 0)).collect())
+            .weekday("Day1")
+            .epoch(Epoch::new("Founding", 1000))
+            .era(Era::new("BE", EraStart::Beginning).backward())
+            .era(Era::new("First Age", EraStart::Year(1000)))
+            .build();
+        let at = |date| calendar.to_elapsed(date, (0, 0, 0), start_datetime).unwrap();
+        
+        // Years before the first age count down to it
+        assert_eq!(calendar.format_date(at((688, 1, 1)), start_datetime, Some("%EY")), "312 BE");
+        assert_eq!(calendar.era_at(at((999, 12, 30))).map(|(era, year)| (era.name.as_str(), year)), Some(("BE", 1)));
+        assert_eq!(calendar.get_era(at((1000, 1, 1)), start_datetime), Some(("First Age".to_string(), 1)));
+        
+        // The second age starts in the middle of year 1590, at the fall of a city
+        let fall = at((1590, 6, 15));
+        let calendar = CustomCalendar {
+            eras: [calendar.eras.clone(), vec![Era::new("Second Age", EraStart::Elapsed(fall))]].concat(),
+            ..calendar.clone()
+        };
+        assert_eq!(calendar.format_date(fall - 1.0, start_datetime, Some("%d %B %Ey %E")), "14 Month6 591 First Age");
+        assert_eq!(calendar.format_date(fall, start_datetime, Some("%d %B %Ey %E (%Y)")), "15 Month6 1 Second Age (1590)");
+        assert_eq!(calendar.format_date(at((1600, 1, 1)), start_datetime, Some("%EY")), "11 Second Age");
+        
+        // Without eras, era tokens use the epoch
+        let calendar = CustomCalendar::builder().month(Month::new("Month1", 30, 0)).weekday("Day1").build();
+        assert_eq!(calendar.get_era(0.0, start_datetime), None);
+        assert_eq!(calendar.format_date(0.0, start_datetime, Some("%EY")), "1 Common Epoch");
+    }
+    
+    #[test]
+    fn test_negative_elapsed() {
+        let start_datetime = chrono::NaiveDateTime::default();
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(20)
+            .month(Month::new("Month1", 30, 0))
+            .weekday("Day1")
+            .epoch(Epoch::new("Test Epoch", 0))
+            .build();
+        
+        // One second before the epoch is the last second of the previous year
+        assert_eq!(calendar.get_date(-1.0, start_datetime), (-1, 1, 30));
+        assert_eq!(calendar.get_time(-1.0, start_datetime), (19, 59, 59));
+        assert_eq!(calendar.get_time(-3600.5, start_datetime), (18, 59, 59));
+        assert_eq!(calendar.format_datetime(-1.0, start_datetime, None), "-001-01-30 19:59:59");
+        
+        assert_eq!(GregorianCalendar.get_time(-0.0005, start_datetime), (23, 59, 59));
+        assert_eq!(GregorianCalendar.get_date(-0.0005, start_datetime), (1969, 12, 31));
+    }
+    
+    #[test]
+    fn test_get_weekday() {
+        // 2024-01-03 is a Wednesday
+        let start_datetime = chrono::NaiveDateTime::new(
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
+            chrono::NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+        );
+        assert_eq!(GregorianCalendar.get_weekday(0.0, start_datetime), (2, "Wednesday".to_string()));
+        assert_eq!(GregorianCalendar.get_weekday(-86400.0 * 3.0, start_datetime), (6, "Sunday".to_string()));
+        assert_eq!(GregorianCalendar.weekday_count(), 7);
+        
+        // The epoch starts on the third weekday, and days before the epoch count backwards
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Month1", 30, 0))
+            .weekdays(vec!["Day1".to_string(), "Day2".to_string(), "Day3".to_string()])
+            .epoch_weekday(2)
+            .build();
+        assert_eq!(calendar.weekday_count(), 3);
+        assert_eq!(calendar.get_weekday(0.0, start_datetime), (2, "Day3".to_string()));
+        assert_eq!(calendar.get_weekday(86400.0 * 1.5, start_datetime), (0, "Day1".to_string()));
+        assert_eq!(calendar.get_weekday(-0.5, start_datetime), (1, "Day2".to_string()));
+        assert_eq!(calendar.get_weekday(-86400.0 * 4.0, start_datetime), (1, "Day2".to_string()));
+        assert_eq!(calendar.format_date(0.0, start_datetime, Some("%A")), "Day3");
+        
+        // Weeks start on the first weekday
+        assert_eq!(calendar.week_start(86400.0 * 1.5, start_datetime), 86400.0);
+        assert_eq!(calendar.week_start(0.0, start_datetime), -86400.0 * 2.0);
+        assert_eq!(calendar.week_start(-0.5, start_datetime), -86400.0 * 2.0);
+    }
+    
+    #[test]
+    fn test_custom_calendar_validation() {
+        let builder = || CustomCalendar::builder().month(Month::new("Month1", 30, 0)).weekday("Day1");
+        assert!(builder().try_build().is_ok());
+        assert_eq!(
+            builder().month(Month::new("Month2", 0, 0)).try_build().unwrap_err(),
+            CalendarError::EmptyMonth { month: 2, name: "Month2".to_string() }
+        );
+        let empty_year = || CustomCalendar::builder().month(Month::new("Month1", 0, 1)).weekday("Day1");
+        assert_eq!(empty_year().leap_years("false").try_build().unwrap_err(), CalendarError::EmptyYear);
+        assert_eq!(empty_year().leap_years("# % 4 == 0").try_build().unwrap_err(), CalendarError::EmptyYear);
+        assert!(empty_year().leap_years("# % 4 == 0").intercalary_day(IntercalaryDay::new("Midwinter", 1, false)).try_build().is_ok());
+        assert_eq!(
+            builder().minutes_per_hour(0).try_build().unwrap_err(),
+            CalendarError::ZeroTimeUnit { field: "minutes_per_hour" }
+        );
+        assert_eq!(
+            builder().intercalary_day(IntercalaryDay::new("Midwinter", 2, false)).try_build().unwrap_err(),
+            CalendarError::UnknownMonth { field: "intercalary_days", month: 2 }
+        );
+        assert_eq!(
+            builder().season(Season::new("Winter", 0)).try_build().unwrap_err(),
+            CalendarError::UnknownMonth { field: "seasons", month: 0 }
+        );
+        let error = builder().leap_months("# %% 4 == 0").try_build().unwrap_err();
+        assert!(matches!(error, CalendarError::InvalidExpression { field: "leap_months", ref expression, .. } if expression == "# %% 4 == 0"));
+        let error = builder().leap_week(LeapWeek::new("# + 1", 1)).try_build().unwrap_err();
+        assert!(matches!(error, CalendarError::InvalidExpression { field: "leap_week", .. }));
+        assert_eq!(
+            builder().era(Era::new("Second Age", EraStart::Year(500))).era(Era::new("First Age", EraStart::Year(1))).try_build().unwrap_err(),
+            CalendarError::UnorderedEra { index: 1, name: "First Age".to_string() }
+        );
+        
+        // Calendars loaded with serde are checked with validate
+        let mut calendar = builder().build();
+        calendar.hours_per_day = 0;
+        assert_eq!(calendar.validate(), Err(CalendarError::ZeroTimeUnit { field: "hours_per_day" }));
+    }
+    
     #[test]
     #[should_panic(expected = "Must have at least one month")]
     fn test_custom_calendar_builder_no_months() {
@@ -834,6 +3089,16 @@ mod tests {
             .build();
     }
     
+    #[test]
+    #[should_panic(expected = "Epoch weekday must be the index of a weekday name")]
+    fn test_custom_calendar_builder_invalid_epoch_weekday() {
+        CustomCalendar::builder()
+            .month(Month::new("Month1", 30, 0))
+            .weekday("Monday")
+            .epoch_weekday(1)
+            .build();
+    }
+    
     #[test]
     #[should_panic(expected = "Must have at least one weekday name")]
     fn test_custom_calendar_builder_no_weekdays() {
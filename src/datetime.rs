@@ -0,0 +1,381 @@
+//! Points in in-game time tied to a calendar.
+
+use crate::calendar::ShiftedCalendar;
+use crate::{Calendar, CalendarDefinition, DateError, GameDuration, InGameClock};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::sync::Arc;
+
+/// A point in in-game time, tied to the calendar of the clock it came from
+///
+/// Date-times are ordered by the instant they represent, can be moved by a
+/// [`GameDuration`], and display with the calendar's default date-time format.
+/// Comparing or subtracting date-times only makes sense for values taken from the
+/// same clock.
+///
+/// # Examples
+/// ```
+/// # use bevy_ingame_clock::{GameDuration, InGameClock};
+/// let clock = InGameClock::with_start_datetime(2024, 6, 15, 8, 0, 0);
+/// let now = clock.now();
+/// let deadline = now.clone() + GameDuration::from_seconds(3600.0);
+///
+/// assert!(deadline > now);
+/// assert_eq!(deadline.to_string(), "2024-06-15 09:00:00");
+/// assert_eq!(deadline.month_name(), "June");
+/// ```
+#[derive(Clone)]
+pub struct GameDateTime {
+    elapsed_seconds: f64,
+    start_datetime: NaiveDateTime,
+    start_offset: f64,
+    /// The calendar of the clock, already shifted by `start_offset`
+    calendar: Arc<dyn Calendar>,
+}
+
+impl GameDateTime {
+    /// Get the elapsed in-game seconds of this date-time on its clock
+    pub fn elapsed_seconds(&self) -> f64 {
+        self.elapsed_seconds
+    }
+
+    /// Get the calendar of this date-time
+    pub fn calendar(&self) -> &Arc<dyn Calendar> {
+        &self.calendar
+    }
+
+    /// Get the date as (year, month, day)
+    pub fn date(&self) -> (i32, u32, u32) {
+        self.calendar.get_date(self.elapsed_seconds, self.start_datetime)
+    }
+
+    /// Get the time as (hour, minute, second)
+    pub fn time(&self) -> (u32, u32, u32) {
+        self.calendar.get_time(self.elapsed_seconds, self.start_datetime)
+    }
+
+    /// Get the year
+    pub fn year(&self) -> i32 {
+        self.date().0
+    }
+
+    /// Get the month, starting at 1
+    pub fn month(&self) -> u32 {
+        self.date().1
+    }
+
+    /// Get the day of the month, starting at 1
+    pub fn day(&self) -> u32 {
+        self.date().2
+    }
+
+    /// Get the hour
+    pub fn hour(&self) -> u32 {
+        self.time().0
+    }
+
+    /// Get the minute
+    pub fn minute(&self) -> u32 {
+        self.time().1
+    }
+
+    /// Get the second
+    pub fn second(&self) -> u32 {
+        self.time().2
+    }
+
+    /// Get the name of the month from [`Calendar::month_names`], or the name of the
+    /// intercalary day if the date belongs to no month, as formatted by `%B`
+    pub fn month_name(&self) -> String {
+        if let Some(name) = self.intercalary_day() {
+            return name;
+        }
+        let index = (self.month() as usize).wrapping_sub(1);
+        self.calendar.month_names().get(index).cloned().unwrap_or_default()
+    }
+
+    /// Get the name of the intercalary day, see [`Calendar::intercalary_day_name`]
+    pub fn intercalary_day(&self) -> Option<String> {
+        self.calendar.intercalary_day_name(self.date())
+    }
+
+    /// Get the day of the week, starting at 0 for the first day of [`Calendar::weekday_names`]
+    pub fn weekday(&self) -> usize {
+        self.calendar.get_weekday(self.elapsed_seconds, self.start_datetime).0
+    }
+
+    /// Get the name of the day of the week from [`Calendar::weekday_names`]
+    pub fn weekday_name(&self) -> String {
+        self.calendar.get_weekday(self.elapsed_seconds, self.start_datetime).1
+    }
+
+    /// Get the era as (name, year counted in the era), see [`Calendar::get_era`]
+    pub fn era(&self) -> Option<(String, i32)> {
+        self.calendar.get_era(self.elapsed_seconds, self.start_datetime)
+    }
+
+    /// Get the day of the year, starting at 1
+    pub fn day_of_year(&self) -> u32 {
+        let day_start = self.calendar.day_start(self.elapsed_seconds, self.start_datetime);
+        let year_start = self.calendar.year_start(self.elapsed_seconds, self.start_datetime);
+        ((day_start - year_start) / self.calendar.seconds_per_day() as f64).round() as u32 + 1
+    }
+
+    /// Format the date-time with the calendar, using its default format for `None`
+    pub fn format(&self, format: Option<&str>) -> String {
+        self.calendar.format_datetime(self.elapsed_seconds, self.start_datetime, format)
+    }
+}
+
+impl InGameClock {
+    /// Gets the current date and time as a [`GameDateTime`]
+    pub fn now(&self) -> GameDateTime {
+        self.datetime_at(self.elapsed_seconds)
+    }
+
+    /// Gets the [`GameDateTime`] at the given elapsed in-game seconds of this clock
+    pub fn datetime_at(&self, elapsed_seconds: f64) -> GameDateTime {
+        GameDateTime {
+            elapsed_seconds,
+            start_datetime: self.start_datetime,
+            start_offset: self.start_offset(),
+            calendar: self.calendar().clone(),
+        }
+    }
+
+    /// Gets the [`GameDateTime`] of a date and time of this clock's calendar
+    ///
+    /// Returns a [`DateError`] if the date or time does not exist in the calendar.
+    pub fn datetime(&self, year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Result<GameDateTime, DateError> {
+        let elapsed_seconds = self.calendar().to_elapsed((year, month, day), (hour, minute, second), self.start_datetime)?;
+        Ok(self.datetime_at(elapsed_seconds))
+    }
+}
+
+impl fmt::Display for GameDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(None))
+    }
+}
+
+impl fmt::Debug for GameDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GameDateTime")
+            .field("elapsed_seconds", &self.elapsed_seconds)
+            .field("datetime", &self.format(None))
+            .finish()
+    }
+}
+
+impl PartialEq for GameDateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for GameDateTime {}
+
+impl PartialOrd for GameDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GameDateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.elapsed_seconds.total_cmp(&other.elapsed_seconds)
+    }
+}
+
+impl Add<GameDuration> for GameDateTime {
+    type Output = Self;
+
+    fn add(mut self, rhs: GameDuration) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl AddAssign<GameDuration> for GameDateTime {
+    fn add_assign(&mut self, rhs: GameDuration) {
+        self.elapsed_seconds += rhs.as_seconds();
+    }
+}
+
+impl Sub<GameDuration> for GameDateTime {
+    type Output = Self;
+
+    fn sub(mut self, rhs: GameDuration) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl SubAssign<GameDuration> for GameDateTime {
+    fn sub_assign(&mut self, rhs: GameDuration) {
+        self.elapsed_seconds -= rhs.as_seconds();
+    }
+}
+
+impl Sub for &GameDateTime {
+    type Output = GameDuration;
+
+    fn sub(self, rhs: Self) -> GameDuration {
+        GameDuration::from_seconds(self.elapsed_seconds - rhs.elapsed_seconds)
+    }
+}
+
+impl Sub for GameDateTime {
+    type Output = GameDuration;
+
+    fn sub(self, rhs: Self) -> GameDuration {
+        &self - &rhs
+    }
+}
+
+/// Serialized form of a [`GameDateTime`], carrying its calendar like a [`crate::ClockSnapshot`]
+#[derive(Serialize, Deserialize)]
+struct GameDateTimeData {
+    elapsed_seconds: f64,
+    start_datetime: NaiveDateTime,
+    #[serde(default)]
+    start_offset: f64,
+    calendar: CalendarDefinition,
+}
+
+impl Serialize for GameDateTime {
+    /// Fails if the calendar does not provide a [`Calendar::definition`]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let calendar = self
+            .calendar
+            .definition()
+            .ok_or_else(|| serde::ser::Error::custom("the calendar of the date-time has no definition"))?;
+        GameDateTimeData {
+            elapsed_seconds: self.elapsed_seconds,
+            start_datetime: self.start_datetime,
+            start_offset: self.start_offset,
+            calendar,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameDateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = GameDateTimeData::deserialize(deserializer)?;
+        Ok(Self {
+            elapsed_seconds: data.elapsed_seconds,
+            start_datetime: data.start_datetime,
+            start_offset: data.start_offset,
+            calendar: ShiftedCalendar::wrap(data.calendar.into_calendar(), data.start_offset),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomCalendar, Epoch, IntercalaryDay, Month};
+
+    fn fantasy_clock() -> InGameClock {
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(20)
+            .month(Month::new("Frostmoon", 20, 1))
+            .month(Month::new("Bloomtide", 21, 0))
+            .weekday("Moonday")
+            .weekday("Fireday")
+            .weekday("Starday")
+            .leap_years("# % 4 == 0")
+            .epoch(Epoch::new("Age of Magic", 1000))
+            .build();
+        InGameClock::new()
+            .with_calendar(calendar)
+            .with_calendar_start(1247, 2, 12, 7, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_datetime_accessors() {
+        let clock = fantasy_clock();
+        let now = clock.now();
+        assert_eq!(now.date(), (1247, 2, 12));
+        assert_eq!(now.time(), (7, 0, 0));
+        assert_eq!(now.month_name(), "Bloomtide");
+        assert_eq!(now.day_of_year(), 32);
+        assert_eq!(clock.datetime(1248, 1, 21, 0, 0, 0).unwrap().day_of_year(), 21);
+
+        // Weekdays of custom calendars count from the first day of the epoch
+        let days_since_epoch = (clock.start_offset() / 72000.0) as usize;
+        assert_eq!(now.weekday(), days_since_epoch % 3);
+        assert_eq!(now.weekday_name(), ["Moonday", "Fireday", "Starday"][days_since_epoch % 3]);
+
+        let gregorian = InGameClock::with_start_datetime(2024, 6, 15, 8, 0, 0).now();
+        assert_eq!(gregorian.weekday_name(), "Saturday");
+        assert_eq!(gregorian.day_of_year(), 167);
+    }
+
+    #[test]
+    fn test_datetime_intercalary_days() {
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Afteryule", 30, 0))
+            .month(Month::new("Foreyule", 30, 0))
+            .weekday("Sterday")
+            .intercalary_day(IntercalaryDay::new("1 Yule", 0, false))
+            .intercalary_day(IntercalaryDay::new("Midyear", 1, false))
+            .intercalary_day(IntercalaryDay::new("2 Yule", 2, false))
+            .intercalary_day(IntercalaryDay::new("3 Yule", 2, false))
+            .build();
+        let clock = InGameClock::new().with_calendar(calendar);
+
+        let yule = clock.datetime_at(0.0);
+        assert_eq!(yule.intercalary_day().as_deref(), Some("1 Yule"));
+        assert_eq!(yule.month_name(), "1 Yule");
+        assert_eq!(yule.day_of_year(), 1);
+
+        let afteryule = clock.datetime(1, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(afteryule.intercalary_day(), None);
+        assert_eq!(afteryule.month_name(), "Afteryule");
+        assert_eq!(afteryule.day_of_year(), 2);
+
+        let midyear = clock.datetime(1, 1, 31, 0, 0, 0).unwrap();
+        assert_eq!(midyear.month_name(), "Midyear");
+        assert_eq!(midyear.day_of_year(), 32);
+
+        let last_day = clock.datetime(1, 2, 32, 12, 0, 0).unwrap();
+        assert_eq!(last_day.month_name(), "3 Yule");
+        assert_eq!(last_day.day_of_year(), 64);
+        let next_year = clock.datetime(2, 0, 1, 0, 0, 0).unwrap();
+        assert_eq!(next_year.month_name(), "1 Yule");
+        assert_eq!(next_year.day_of_year(), 1);
+    }
+
+    #[test]
+    fn test_datetime_arithmetic() {
+        let clock = fantasy_clock();
+        let now = clock.now();
+        let later = now.clone() + GameDuration::from_seconds(13.0 * 3600.0);
+        assert_eq!(later.date(), (1247, 2, 13));
+        assert_eq!(later.to_string(), "1247-02-13 00:00:00");
+        assert!(later > now);
+        assert_eq!(&later - &now, GameDuration::from_seconds(13.0 * 3600.0));
+        assert_eq!(later.clone() - GameDuration::from_seconds(13.0 * 3600.0), now);
+
+        let mut timestamps = [later.clone(), now.clone(), clock.datetime(1247, 2, 12, 10, 0, 0).unwrap()];
+        timestamps.sort();
+        assert_eq!(timestamps[0], now);
+        assert_eq!(timestamps[2], later);
+    }
+
+    #[test]
+    fn test_datetime_serde() {
+        let clock = fantasy_clock();
+        let now = clock.now() + GameDuration::from_seconds(60.0);
+        let serialized = ron::to_string(&now).unwrap();
+        let deserialized: GameDateTime = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, now);
+        assert_eq!(deserialized.to_string(), "1247-02-12 07:01:00");
+    }
+}
@@ -0,0 +1,293 @@
+//! Spans of in-game time.
+
+use crate::{Calendar, InGameClock, TimeUnit};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// Units used to decompose and format durations, from the largest to the smallest
+const UNITS: [TimeUnit; 5] = [TimeUnit::Week, TimeUnit::Day, TimeUnit::Hour, TimeUnit::Minute, TimeUnit::Second];
+
+/// Error returned when a duration string cannot be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DurationParseError {
+    /// The string contains no duration
+    Empty,
+    /// A part of the string does not start with a number
+    InvalidNumber(String),
+    /// A number is followed by a missing or unknown unit
+    UnknownUnit(String),
+}
+
+impl fmt::Display for DurationParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationParseError::Empty => write!(f, "empty duration"),
+            DurationParseError::InvalidNumber(value) => write!(f, "invalid number: '{}'", value),
+            DurationParseError::UnknownUnit(unit) => write!(f, "unknown time unit: '{}'", unit),
+        }
+    }
+}
+
+impl std::error::Error for DurationParseError {}
+
+/// A span of in-game time, stored in in-game seconds
+///
+/// Durations are added to and subtracted from a [`crate::GameDateTime`], and the
+/// difference between two date-times is a duration. They can be built from, split
+/// into and formatted in the units of a calendar, so "3 days" lasts three days of
+/// whichever calendar is passed in.
+///
+/// # Examples
+/// ```
+/// # use bevy_ingame_clock::{CustomCalendar, GameDuration, Month};
+/// let calendar = CustomCalendar::builder()
+///     .minutes_per_hour(20)
+///     .hours_per_day(20)
+///     .month(Month::new("Frostmoon", 30, 0))
+///     .weekdays(vec!["Moonday".into(), "Fireday".into(), "Starday".into(), "Earthday".into(), "Waterday".into()])
+///     .build();
+///
+/// let journey = GameDuration::parse("3 days 4 hours", &calendar).unwrap();
+/// assert_eq!(journey.as_seconds(), 3.0 * 24000.0 + 4.0 * 1200.0);
+///
+/// let siege = GameDuration::parse("7d", &calendar).unwrap();
+/// assert_eq!(siege.format(&calendar), "1 week, 2 days");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct GameDuration {
+    seconds: f64,
+}
+
+impl GameDuration {
+    /// A duration of no time
+    pub const ZERO: Self = Self { seconds: 0.0 };
+
+    /// Creates a duration of the given number of in-game seconds
+    pub fn from_seconds(seconds: f64) -> Self {
+        Self { seconds }
+    }
+
+    /// Creates a duration of `amount` of the given calendar unit
+    pub fn from_unit(amount: f64, unit: TimeUnit, calendar: &dyn Calendar) -> Self {
+        Self::from_seconds(amount * unit.as_seconds(calendar))
+    }
+
+    /// Parse a duration such as `"2d 5h"`, `"1.5 hours"` or `"1 week, 2 days"`
+    ///
+    /// The string is a list of numbers, each followed by a unit: `w`/`week`, `d`/`day`,
+    /// `h`/`hour`, `m`/`min`/`minute` or `s`/`sec`/`second`, with optional plural `s`,
+    /// in any case. Parts may be separated by spaces or commas. Units are resolved
+    /// against the calendar.
+    pub fn parse(text: &str, calendar: &dyn Calendar) -> Result<Self, DurationParseError> {
+        let mut rest = text.trim();
+        if rest.is_empty() {
+            return Err(DurationParseError::Empty);
+        }
+
+        let mut seconds = 0.0;
+        while !rest.is_empty() {
+            let number_end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+            let word_end = rest.find(|c: char| c.is_whitespace() || c == ',').unwrap_or(rest.len());
+            let amount: f64 = rest[..number_end]
+                .parse()
+                .map_err(|_| DurationParseError::InvalidNumber(rest[..word_end].to_string()))?;
+
+            rest = rest[number_end..].trim_start();
+            let unit_end = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+            let unit = parse_unit(&rest[..unit_end]).ok_or_else(|| DurationParseError::UnknownUnit(rest[..unit_end].to_string()))?;
+            seconds += amount * unit.as_seconds(calendar);
+
+            rest = rest[unit_end..].trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        }
+        Ok(Self::from_seconds(seconds))
+    }
+
+    /// Get the length of this duration in in-game seconds
+    pub fn as_seconds(&self) -> f64 {
+        self.seconds
+    }
+
+    /// Get the length of this duration in the given calendar unit, including fractions
+    pub fn as_unit(&self, unit: TimeUnit, calendar: &dyn Calendar) -> f64 {
+        self.seconds / unit.as_seconds(calendar)
+    }
+
+    /// Split this duration into whole weeks, days, hours, minutes and seconds of the calendar
+    ///
+    /// Only units with a non-zero amount are returned, from the largest to the smallest.
+    /// Fractions of a second and the sign of negative durations are dropped.
+    pub fn split(&self, calendar: &dyn Calendar) -> Vec<(u64, TimeUnit)> {
+        let mut remaining = self.seconds.abs().floor();
+        let mut parts = Vec::new();
+        for unit in UNITS {
+            let unit_seconds = unit.as_seconds(calendar);
+            let amount = (remaining / unit_seconds).floor();
+            if amount > 0.0 {
+                parts.push((amount as u64, unit));
+                remaining -= amount * unit_seconds;
+            }
+        }
+        parts
+    }
+
+    /// Format this duration for display, such as `"1 week, 2 days"`, using the calendar's units
+    pub fn format(&self, calendar: &dyn Calendar) -> String {
+        let parts = self.split(calendar);
+        if parts.is_empty() {
+            return "0 seconds".to_string();
+        }
+
+        let text = parts
+            .iter()
+            .map(|(amount, unit)| {
+                let name = unit_name(*unit);
+                if *amount == 1 {
+                    format!("1 {}", name)
+                } else {
+                    format!("{} {}s", amount, name)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        if self.seconds < 0.0 {
+            format!("-{}", text)
+        } else {
+            text
+        }
+    }
+}
+
+/// Resolve a unit of a duration string
+fn parse_unit(unit: &str) -> Option<TimeUnit> {
+    match unit.to_ascii_lowercase().as_str() {
+        "w" | "week" | "weeks" => Some(TimeUnit::Week),
+        "d" | "day" | "days" => Some(TimeUnit::Day),
+        "h" | "hour" | "hours" => Some(TimeUnit::Hour),
+        "m" | "min" | "mins" | "minute" | "minutes" => Some(TimeUnit::Minute),
+        "s" | "sec" | "secs" | "second" | "seconds" => Some(TimeUnit::Second),
+        _ => None,
+    }
+}
+
+/// Get the singular name of a unit for formatted durations
+fn unit_name(unit: TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "second",
+        TimeUnit::Minute => "minute",
+        TimeUnit::Hour => "hour",
+        TimeUnit::Day => "day",
+        TimeUnit::Week => "week",
+    }
+}
+
+impl InGameClock {
+    /// Moves the clock forward by a duration, or backwards for a negative one
+    pub fn advance_by(&mut self, duration: GameDuration) {
+        self.elapsed_seconds += duration.as_seconds();
+    }
+
+    /// Parse a duration string in the units of this clock's calendar, see [`GameDuration::parse`]
+    pub fn parse_duration(&self, text: &str) -> Result<GameDuration, DurationParseError> {
+        GameDuration::parse(text, self.calendar().as_ref())
+    }
+
+    /// Format a duration in the units of this clock's calendar, see [`GameDuration::format`]
+    pub fn format_duration(&self, duration: GameDuration) -> String {
+        duration.format(self.calendar().as_ref())
+    }
+}
+
+impl Add for GameDuration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_seconds(self.seconds + rhs.seconds)
+    }
+}
+
+impl AddAssign for GameDuration {
+    fn add_assign(&mut self, rhs: Self) {
+        self.seconds += rhs.seconds;
+    }
+}
+
+impl Sub for GameDuration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_seconds(self.seconds - rhs.seconds)
+    }
+}
+
+impl SubAssign for GameDuration {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.seconds -= rhs.seconds;
+    }
+}
+
+impl Neg for GameDuration {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_seconds(-self.seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomCalendar, GregorianCalendar, Month};
+
+    fn short_day_calendar() -> CustomCalendar {
+        CustomCalendar::builder()
+            .minutes_per_hour(20)
+            .hours_per_day(20)
+            .month(Month::new("Frostmoon", 30, 0))
+            .weekdays(vec!["Moonday".into(), "Fireday".into(), "Starday".into(), "Earthday".into(), "Waterday".into()])
+            .build()
+    }
+
+    #[test]
+    fn test_duration_parse() {
+        let calendar = short_day_calendar();
+        let parse = |text: &str| GameDuration::parse(text, &calendar).map(|duration| duration.as_seconds());
+        assert_eq!(parse("2d 5h"), Ok(2.0 * 24000.0 + 5.0 * 1200.0));
+        assert_eq!(parse("1 Week, 2 days"), Ok(7.0 * 24000.0));
+        assert_eq!(parse("1.5h 30s"), Ok(1830.0));
+        assert_eq!(parse("3 minutes"), Ok(180.0));
+        assert_eq!(GameDuration::parse("1w", &GregorianCalendar).unwrap().as_seconds(), 604800.0);
+
+        assert_eq!(parse("  "), Err(DurationParseError::Empty));
+        assert_eq!(parse("2d five"), Err(DurationParseError::InvalidNumber("five".to_string())));
+        assert_eq!(parse("2 fortnights"), Err(DurationParseError::UnknownUnit("fortnights".to_string())));
+        assert_eq!(parse("2"), Err(DurationParseError::UnknownUnit(String::new())));
+    }
+
+    #[test]
+    fn test_duration_split_and_format() {
+        let calendar = short_day_calendar();
+        let duration = GameDuration::from_unit(7.0, TimeUnit::Day, &calendar) + GameDuration::from_seconds(61.5);
+        assert_eq!(
+            duration.split(&calendar),
+            vec![(1, TimeUnit::Week), (2, TimeUnit::Day), (1, TimeUnit::Minute), (1, TimeUnit::Second)]
+        );
+        assert_eq!(duration.format(&calendar), "1 week, 2 days, 1 minute, 1 second");
+        assert_eq!(duration.as_unit(TimeUnit::Day, &calendar), 7.0 + 61.5 / 24000.0);
+
+        assert_eq!(GameDuration::ZERO.format(&calendar), "0 seconds");
+        assert_eq!((-GameDuration::from_unit(3.0, TimeUnit::Hour, &calendar)).format(&calendar), "-3 hours");
+        // The same duration spans more days in a calendar with shorter days
+        assert_eq!(GameDuration::from_seconds(86400.0).format(&GregorianCalendar), "1 day");
+        assert_eq!(GameDuration::from_seconds(86400.0).format(&calendar), "3 days, 12 hours");
+    }
+
+    #[test]
+    fn test_advance_by() {
+        let mut clock = InGameClock::with_start_datetime(2024, 1, 1, 0, 0, 0);
+        let duration = clock.parse_duration("1d 6h").unwrap();
+        clock.advance_by(duration);
+        assert_eq!(clock.format_datetime(None), "2024-01-02 06:00:00");
+        assert_eq!(clock.format_duration(duration), "1 day, 6 hours");
+    }
+}
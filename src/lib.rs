@@ -16,12 +16,34 @@
 //! }
 //! ```
 
+mod alarm;
+#[cfg(feature = "asset")]
+mod asset;
 mod calendar;
+mod datetime;
+mod duration;
+mod schedule;
+mod snapshot;
+mod timer;
 
-pub use calendar::{Calendar, GregorianCalendar, Month, Epoch, CustomCalendar, CustomCalendarBuilder};
+pub use alarm::{AlarmFired, AlarmId, AlarmTime, ClockAlarm};
+#[cfg(feature = "asset")]
+pub use asset::{CalendarAsset, CalendarAssetLoader, CalendarAssetPlugin, CalendarLoaderError, CalendarReloadError, CalendarReloaded};
+pub use calendar::{Calendar, CalendarDefinition, CalendarError, DateError, Era, EraDirection, EraStart, GregorianCalendar, IntercalaryDay, LeapWeek, Month, Epoch, Season, CustomCalendar, CustomCalendarBuilder};
+pub use datetime::GameDateTime;
+pub use duration::{DurationParseError, GameDuration};
+pub use schedule::{ClockSchedule, ScheduleError, ScheduleFired, ScheduleId};
+pub use snapshot::{ClockSnapshot, IntervalTrackerSnapshot};
+pub use timer::{InGameTimer, TimeUnit, TimerFinished};
 
+use alarm::ClockAlarms;
+use calendar::ShiftedCalendar;
+use schedule::ClockSchedules;
 use bevy::prelude::*;
 use chrono::{Duration, NaiveDateTime, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 /// Event fired when a specific time interval has passed
@@ -31,14 +53,102 @@ pub struct ClockIntervalEvent {
     pub interval: ClockInterval,
     /// The number of times this interval has passed since the clock started
     pub count: u64,
+    /// How the boundaries of the interval are aligned
+    pub alignment: IntervalAlignment,
+    /// The elapsed in-game seconds of the boundary that triggered this event
+    pub elapsed_seconds: f64,
+    /// The date of the boundary as (year, month, day) in the clock's calendar
+    pub date: (i32, u32, u32),
+    /// The time of the boundary as (hour, minute, second) in the clock's calendar
+    pub time: (u32, u32, u32),
+    /// How many in-game seconds the clock had already moved past the boundary when the
+    /// event was sent. Several events sent in one frame have decreasing lateness.
+    pub lateness: f64,
+    /// The number of earlier boundaries folded into this event by [`CatchUpPolicy::Coalesce`]
+    pub missed: u64,
+}
+
+/// Defines where the boundaries of fixed-length intervals are placed
+///
+/// Calendar-aligned intervals ([`ClockInterval::Month`], [`ClockInterval::Year`] and
+/// [`ClockInterval::Season`]) always follow the calendar, regardless of the alignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum IntervalAlignment {
+    /// Boundaries at multiples of the interval since the clock started.
+    /// With a start time of 08:30, `Day` fires at 08:30 every day.
+    #[default]
+    SinceStart,
+    /// Boundaries on the calendar: midnight for days, :00 for hours, the first weekday
+    /// for weeks. `Custom` intervals are counted from midnight each day.
+    AlignToCalendar,
+}
+
+/// Defines what happens when several boundaries of an interval pass in a single frame
+///
+/// This happens at high clock speeds or when `elapsed_seconds` jumps ahead. When the
+/// clock moves backwards, pending boundaries are dropped and the interval continues
+/// from the new time without firing, regardless of the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum CatchUpPolicy {
+    /// Send one event for every boundary passed
+    #[default]
+    FireAll,
+    /// Send at most this many events per frame, and deliver the remaining boundaries
+    /// in the following frames. `Cap(0)` is treated as `Cap(1)`, so the interval keeps firing.
+    Cap(u32),
+    /// Send a single event for the latest boundary passed, with
+    /// [`ClockIntervalEvent::missed`] counting the boundaries before it
+    Coalesce,
+}
+
+/// Settings for a registered interval
+///
+/// # Examples
+/// ```
+/// # use bevy_ingame_clock::{CatchUpPolicy, IntervalAlignment, IntervalSettings};
+/// let settings = IntervalSettings::default()
+///     .with_alignment(IntervalAlignment::AlignToCalendar)
+///     .with_catch_up(CatchUpPolicy::Coalesce);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct IntervalSettings {
+    /// How the boundaries of the interval are aligned
+    pub alignment: IntervalAlignment,
+    /// What happens when several boundaries pass in a single frame
+    #[serde(default)]
+    pub catch_up: CatchUpPolicy,
+}
+
+impl IntervalSettings {
+    /// Sets how the boundaries of the interval are aligned
+    pub fn with_alignment(mut self, alignment: IntervalAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets what happens when several boundaries pass in a single frame
+    pub fn with_catch_up(mut self, catch_up: CatchUpPolicy) -> Self {
+        self.catch_up = catch_up;
+        self
+    }
 }
 
 /// Defines different time intervals for events
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// # Examples
+/// ```
+/// # use bevy_ingame_clock::{ClockInterval, GregorianCalendar, TimeUnit};
+/// // Combat ticks four times per in-game second
+/// let tick = ClockInterval::every(0.25, TimeUnit::Second);
+/// // Every three hours of the active calendar
+/// let watch = ClockInterval::every(3, TimeUnit::Hour);
+/// assert_eq!(watch.as_seconds(&GregorianCalendar), Some(10800.0));
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum ClockInterval {
     /// Every second
     Second,
-    /// Every minute
+    /// Every minute of the calendar
     Minute,
     /// Every hour
     Hour,
@@ -46,24 +156,238 @@ pub enum ClockInterval {
     Day,
     /// Every week
     Week,
-    /// Custom interval in seconds
+    /// Custom interval in whole seconds
     Custom(u32),
+    /// Every given amount of a calendar unit, which may be fractional (see [`ClockInterval::every`])
+    Every(f64, TimeUnit),
+    /// Every time a new month starts in the calendar
+    Month,
+    /// Every time a new year starts in the calendar
+    Year,
+    /// Every time a new season starts (see [`Calendar::seasons`])
+    Season,
+}
+
+// Intervals are used as keys for shared trackers. Amounts are compared and hashed by their
+// bits, so that equality is reflexive for NaN and agrees with the hash for -0.0.
+impl PartialEq for ClockInterval {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ClockInterval::Custom(seconds), ClockInterval::Custom(other_seconds)) => seconds == other_seconds,
+            (ClockInterval::Every(amount, unit), ClockInterval::Every(other_amount, other_unit)) => {
+                amount.to_bits() == other_amount.to_bits() && unit == other_unit
+            }
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for ClockInterval {}
+
+impl Hash for ClockInterval {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            ClockInterval::Custom(seconds) => seconds.hash(state),
+            ClockInterval::Every(amount, unit) => {
+                amount.to_bits().hash(state);
+                unit.hash(state);
+            }
+            _ => {}
+        }
+    }
 }
 
 impl ClockInterval {
+    /// Creates an interval lasting `amount` of the given calendar unit
+    ///
+    /// The unit is resolved against the active calendar, so `every(3, TimeUnit::Hour)`
+    /// follows the calendar's hour length. Amounts may be fractional, such as
+    /// `every(0.25, TimeUnit::Second)`. Amounts that are not strictly positive, including
+    /// NaN, are stored as `0.0`, and such intervals never fire.
+    pub fn every(amount: impl Into<f64>, unit: TimeUnit) -> Self {
+        let amount = amount.into();
+        ClockInterval::Every(if amount > 0.0 { amount } else { 0.0 }, unit)
+    }
+
     /// Get the duration of this interval in seconds, based on the calendar
-    pub fn as_seconds(&self, calendar: &dyn Calendar) -> u32 {
+    ///
+    /// Returns `None` for [`ClockInterval::Month`], [`ClockInterval::Year`] and
+    /// [`ClockInterval::Season`], whose length varies with the calendar date.
+    pub fn as_seconds(&self, calendar: &dyn Calendar) -> Option<f64> {
         match self {
-            ClockInterval::Second => 1,
-            ClockInterval::Minute => 60,
-            ClockInterval::Hour => calendar.seconds_per_hour(),
-            ClockInterval::Day => calendar.seconds_per_day(),
-            ClockInterval::Week => calendar.seconds_per_week(),
-            ClockInterval::Custom(seconds) => *seconds,
+            ClockInterval::Second => Some(1.0),
+            ClockInterval::Minute => Some(calendar.seconds_per_minute() as f64),
+            ClockInterval::Hour => Some(calendar.seconds_per_hour() as f64),
+            ClockInterval::Day => Some(calendar.seconds_per_day() as f64),
+            ClockInterval::Week => Some(calendar.seconds_per_week() as f64),
+            ClockInterval::Custom(seconds) => Some(*seconds as f64),
+            ClockInterval::Every(amount, unit) => Some(amount * unit.as_seconds(calendar)),
+            ClockInterval::Month | ClockInterval::Year | ClockInterval::Season => None,
+        }
+    }
+
+    /// Get the elapsed seconds of the first boundary of this interval strictly after `after`
+    ///
+    /// Fixed-length intervals have boundaries at multiples of their duration, counted
+    /// from the clock start or from the calendar depending on `alignment`. Month, year
+    /// and season boundaries are found where [`Calendar::get_date`] rolls over, so they
+    /// respect varying month lengths and leap days.
+    pub fn next_boundary(
+        &self,
+        after: f64,
+        alignment: IntervalAlignment,
+        calendar: &dyn Calendar,
+        start_datetime: NaiveDateTime,
+    ) -> f64 {
+        match self {
+            ClockInterval::Month => next_date_change(after, calendar, start_datetime, |(year, month, _)| (year, month)),
+            ClockInterval::Year => next_date_change(after, calendar, start_datetime, |(year, _, _)| (year, 0)),
+            ClockInterval::Season => {
+                let seasons = calendar.seasons();
+                if seasons.is_empty() {
+                    return f64::INFINITY;
+                }
+                next_date_change(after, calendar, start_datetime, |(year, month, _)| {
+                    // Order seasons by the year they started in, then by their start month
+                    let (index, year_offset) = Season::find(&seasons, month).unwrap();
+                    (year + year_offset, seasons[index].start_month)
+                })
+            }
+            ClockInterval::Week if alignment == IntervalAlignment::AlignToCalendar => {
+                // Weeks can last longer than `seconds_per_week` when days are left out of them
+                let week_start = calendar.week_start(after, start_datetime);
+                let mut next = week_start + calendar.seconds_per_week() as f64;
+                while calendar.week_start(next, start_datetime) <= week_start {
+                    next += calendar.seconds_per_day() as f64;
+                }
+                calendar.week_start(next, start_datetime)
+            }
+            _ => {
+                let interval_seconds = self.as_seconds(calendar).unwrap();
+                if !(interval_seconds > 0.0 && interval_seconds.is_finite()) {
+                    return f64::INFINITY;
+                }
+                match alignment {
+                    IntervalAlignment::SinceStart => next_multiple(after, 0.0, interval_seconds),
+                    IntervalAlignment::AlignToCalendar => {
+                        // Count from the start of the day, and restart at the next midnight
+                        let day_start = calendar.day_start(after, start_datetime);
+                        let next = next_multiple(after, day_start, interval_seconds);
+                        next.min(day_start + calendar.seconds_per_day() as f64)
+                    }
+                }
+            }
         }
     }
 }
 
+/// Find the first `origin + k * step` strictly after `after`
+///
+/// Fractional steps are not exact in floating point, so the division can land just
+/// below a boundary that has already passed. Skipping it keeps boundaries advancing.
+fn next_multiple(after: f64, origin: f64, step: f64) -> f64 {
+    let mut index = ((after - origin) / step).floor() + 1.0;
+    if origin + index * step <= after {
+        index += 1.0;
+    }
+    origin + index * step
+}
+
+/// Find the start of the first day after `after` on which `key` of the date changes
+///
+/// Dates only change at day starts, and `key` must never decrease as time moves on,
+/// so an exponential search followed by a binary search over whole days finds the
+/// change with a logarithmic number of [`Calendar::get_date`] calls.
+fn next_date_change(
+    after: f64,
+    calendar: &dyn Calendar,
+    start_datetime: NaiveDateTime,
+    key: impl Fn((i32, u32, u32)) -> (i32, u32),
+) -> f64 {
+    /// Upper bound for the search, far beyond the length of any sensible year
+    const MAX_DAYS: u64 = 1 << 20;
+
+    let seconds_per_day = calendar.seconds_per_day() as f64;
+    let day_start = calendar.day_start(after, start_datetime);
+    let current = key(calendar.get_date(after, start_datetime));
+    let changed = |days: u64| {
+        let elapsed = day_start + days as f64 * seconds_per_day;
+        key(calendar.get_date(elapsed, start_datetime)) != current
+    };
+
+    // Find a day on which the key has changed
+    let mut high = 1;
+    while !changed(high) {
+        if high >= MAX_DAYS {
+            return f64::INFINITY;
+        }
+        high *= 2;
+    }
+
+    // Narrow down to the first such day
+    let mut low = high / 2;
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if changed(mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    day_start + high as f64 * seconds_per_day
+}
+
+/// Source of unique ids for [`IntervalHandle`]s
+static NEXT_INTERVAL_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// Handle to a registered clock interval
+///
+/// Returned when registering an interval. Several handles can refer to the same
+/// [`ClockInterval`]; its tracker keeps firing events until every handle has been
+/// unregistered. Handles are compared and hashed by their unique id only.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct IntervalHandle {
+    id: u64,
+    interval: ClockInterval,
+    settings: IntervalSettings,
+}
+
+impl PartialEq for IntervalHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for IntervalHandle {}
+
+impl Hash for IntervalHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl IntervalHandle {
+    fn new(interval: ClockInterval, settings: IntervalSettings) -> Self {
+        Self {
+            id: NEXT_INTERVAL_HANDLE.fetch_add(1, Ordering::Relaxed),
+            interval,
+            settings,
+        }
+    }
+
+    /// Get the interval this handle refers to
+    pub fn interval(&self) -> ClockInterval {
+        self.interval
+    }
+
+    /// Get the settings the interval was registered with
+    pub fn settings(&self) -> IntervalSettings {
+        self.settings
+    }
+}
+
 /// The main plugin for the in-game clock system.
 ///
 /// Add this plugin to your Bevy app to enable in-game clock functionality.
@@ -73,9 +397,18 @@ impl Plugin for InGameClockPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<InGameClock>()
             .init_resource::<ClockIntervalTrackers>()
+            .init_resource::<ClockAlarms>()
+            .init_resource::<ClockSchedules>()
             .add_message::<ClockIntervalEvent>()
-            .add_systems(Update, update_clock)
-            .add_systems(Update, check_intervals);
+            .add_message::<AlarmFired>()
+            .add_message::<ScheduleFired>()
+            .add_systems(
+                Update,
+                (
+                    update_clock,
+                    (check_intervals, alarm::check_alarms, schedule::check_schedules, timer::tick_timers).after(update_clock),
+                ),
+            );
     }
 }
 
@@ -87,8 +420,11 @@ struct ClockIntervalTrackers {
 
 struct IntervalTracker {
     interval: ClockInterval,
+    settings: IntervalSettings,
     last_trigger_seconds: f64,
     count: u64,
+    /// Handles currently owning this tracker; it is dropped when the last one is released
+    owners: Vec<IntervalHandle>,
 }
 
 /// Resource that represents the in-game clock.
@@ -104,8 +440,15 @@ pub struct InGameClock {
     pub paused: bool,
     /// The start date/time for the in-game clock
     pub start_datetime: NaiveDateTime,
-    /// The calendar system used for date/time calculations and formatting
+    /// The calendar system used for date/time calculations and formatting, seen from the clock start
     calendar: Arc<dyn Calendar>,
+    /// The calendar system as configured, counting from its own origin
+    base_calendar: Arc<dyn Calendar>,
+    /// Seconds from the origin of the calendar to the start of the clock
+    start_offset: f64,
+    /// The calendar asset this clock follows, applied by [`CalendarAssetPlugin`]
+    #[cfg(feature = "asset")]
+    calendar_asset: Option<Handle<CalendarAsset>>,
 }
 
 impl std::fmt::Debug for InGameClock {
@@ -115,6 +458,7 @@ impl std::fmt::Debug for InGameClock {
             .field("speed", &self.speed)
             .field("paused", &self.paused)
             .field("start_datetime", &self.start_datetime)
+            .field("start_offset", &self.start_offset)
             .field("calendar", &"<Calendar>")
             .finish()
     }
@@ -131,6 +475,10 @@ impl Default for InGameClock {
             paused: false,
             start_datetime: now,
             calendar: Arc::new(GregorianCalendar),
+            base_calendar: Arc::new(GregorianCalendar),
+            start_offset: 0.0,
+            #[cfg(feature = "asset")]
+            calendar_asset: None,
         }
     }
 }
@@ -143,29 +491,81 @@ impl InGameClock {
 
     /// Register an interval to trigger events
     ///
+    /// Registering an interval that is already tracked does not create a second tracker,
+    /// but the returned handle keeps the tracker alive until it is unregistered.
+    /// The interval uses the default [`IntervalSettings`].
+    ///
     /// # Examples
     /// ```
     /// # use bevy::prelude::*;
-    /// # use bevy_ingame_clock::{InGameClock, ClockInterval};
-    /// fn setup(mut commands: Commands) {
-    ///     let mut clock = InGameClock::new();
-    ///     // Register to receive events every in-game hour
-    ///     commands.insert_resource(clock);
-    /// }
+    /// # use bevy_ingame_clock::{InGameClock, InGameClockPlugin, ClockInterval};
+    /// let mut app = App::new();
+    /// app.add_plugins(InGameClockPlugin);
+    ///
+    /// // Register to receive events every in-game hour
+    /// let handle = InGameClock::register_interval(app.world_mut(), ClockInterval::Hour);
+    ///
+    /// // Stop receiving them again
+    /// InGameClock::unregister_interval(app.world_mut(), handle);
     /// ```
-    pub fn register_interval(world: &mut World, interval: ClockInterval) {
+    pub fn register_interval(world: &mut World, interval: ClockInterval) -> IntervalHandle {
+        Self::register_interval_with(world, interval, IntervalSettings::default())
+    }
+
+    /// Register an interval with custom [`IntervalSettings`] to trigger events
+    ///
+    /// The same interval registered with different settings gets its own tracker.
+    pub fn register_interval_with(world: &mut World, interval: ClockInterval, settings: IntervalSettings) -> IntervalHandle {
+        let handle = IntervalHandle::new(interval, settings);
+        Self::register_interval_handle(world, handle);
+        handle
+    }
+
+    fn register_interval_handle(world: &mut World, handle: IntervalHandle) {
         let mut trackers = world.resource_mut::<ClockIntervalTrackers>();
         
-        // Don't register duplicates
-        if !trackers.trackers.iter().any(|t| t.interval == interval) {
+        // Share the tracker of an already registered interval
+        if let Some(tracker) = trackers
+            .trackers
+            .iter_mut()
+            .find(|t| t.interval == handle.interval && t.settings == handle.settings)
+        {
+            tracker.owners.push(handle);
+        } else {
             trackers.trackers.push(IntervalTracker {
-                interval,
+                interval: handle.interval,
+                settings: handle.settings,
                 last_trigger_seconds: 0.0,
                 count: 0,
+                owners: vec![handle],
             });
         }
     }
 
+    /// Release a handle returned by [`InGameClock::register_interval`]
+    ///
+    /// The interval stops firing events once all of its handles have been released.
+    /// Unregistering a handle that was already released does nothing.
+    pub fn unregister_interval(world: &mut World, handle: IntervalHandle) {
+        let mut trackers = world.resource_mut::<ClockIntervalTrackers>();
+        
+        // Only drop the tracker this handle kept alive, not other trackers without owners,
+        // such as ones restored from a snapshot that did not record them
+        let Some(index) = trackers.trackers.iter().position(|t| t.owners.contains(&handle)) else {
+            return;
+        };
+        let tracker = &mut trackers.trackers[index];
+        tracker.owners.retain(|owner| *owner != handle);
+        if tracker.owners.is_empty() {
+            trackers.trackers.remove(index);
+        }
+    }
+
+    /// Remove every registered interval, regardless of how many handles own it
+    pub fn clear_intervals(world: &mut World) {
+        world.resource_mut::<ClockIntervalTrackers>().trackers.clear();
+    }
+
     /// Creates a new in-game clock with a specific start date and time
     pub fn with_start_datetime(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Self {
         let start_datetime = NaiveDateTime::new(
@@ -174,20 +574,80 @@ impl InGameClock {
         );
         
         Self {
-            elapsed_seconds: 0.0,
-            speed: 1.0,
-            paused: false,
             start_datetime,
-            calendar: Arc::new(GregorianCalendar),
+            ..Self::default()
         }
     }
 
     /// Creates a new in-game clock with a custom calendar system
+    ///
+    /// The clock starts at the origin of the new calendar; use
+    /// [`InGameClock::with_calendar_start`] afterwards to start it at another date.
     pub fn with_calendar(mut self, calendar: impl Calendar + 'static) -> Self {
-        self.calendar = Arc::new(calendar);
+        self.set_calendar(Arc::new(calendar), 0.0);
         self
     }
 
+    /// Starts the clock at a date and time of its calendar
+    ///
+    /// The date is validated against the month lengths and leap rules of the calendar,
+    /// so set the calendar with [`InGameClock::with_calendar`] first. For the
+    /// [`GregorianCalendar`] this sets `start_datetime`; other calendars count from their
+    /// own origin, such as the epoch of a [`CustomCalendar`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ingame_clock::{CustomCalendar, Epoch, InGameClock, Month};
+    /// let calendar = CustomCalendar::builder()
+    ///     .month(Month::new("Frostmoon", 20, 0))
+    ///     .month(Month::new("Bloomtide", 21, 0))
+    ///     .weekday("Moonday")
+    ///     .epoch(Epoch::new("Age of Magic", 1000))
+    ///     .build();
+    /// let clock = InGameClock::new()
+    ///     .with_calendar(calendar.clone())
+    ///     .with_calendar_start(1247, 2, 12, 7, 0, 0)
+    ///     .unwrap();
+    /// assert_eq!(clock.current_date(), (1247, 2, 12));
+    ///
+    /// // Bloomtide only has 21 days
+    /// assert!(InGameClock::new().with_calendar(calendar).with_calendar_start(1247, 2, 22, 0, 0, 0).is_err());
+    /// ```
+    pub fn with_calendar_start(
+        mut self,
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> Result<Self, DateError> {
+        if matches!(self.base_calendar.definition(), Some(CalendarDefinition::Gregorian)) {
+            let date = chrono::NaiveDate::from_ymd_opt(year, month, day).ok_or(DateError::InvalidDate { year, month, day })?;
+            let time = chrono::NaiveTime::from_hms_opt(hour, minute, second).ok_or(DateError::InvalidTime { hour, minute, second })?;
+            self.start_datetime = NaiveDateTime::new(date, time);
+            self.set_calendar(self.base_calendar.clone(), 0.0);
+        } else {
+            let offset = self.base_calendar.to_elapsed((year, month, day), (hour, minute, second), self.start_datetime)?;
+            self.set_calendar(self.base_calendar.clone(), offset);
+        }
+        Ok(self)
+    }
+
+    /// Gets the seconds from the origin of the calendar to the start of the clock
+    ///
+    /// This is 0 unless the clock was started with [`InGameClock::with_calendar_start`]
+    /// on a calendar that counts from its own epoch.
+    pub fn start_offset(&self) -> f64 {
+        self.start_offset
+    }
+
+    pub(crate) fn set_calendar(&mut self, calendar: Arc<dyn Calendar>, start_offset: f64) {
+        self.calendar = ShiftedCalendar::wrap(calendar.clone(), start_offset);
+        self.base_calendar = calendar;
+        self.start_offset = start_offset;
+    }
+
     /// Sets the clock speed multiplier
     pub fn with_speed(mut self, speed: f32) -> Self {
         self.speed = speed;
@@ -259,9 +719,28 @@ impl InGameClock {
         calendar_seconds_per_day / self.speed
     }
 
+    /// Sets the clock to a date and time of its calendar
+    ///
+    /// Returns a [`DateError`] and leaves the clock unchanged if the date or time does not
+    /// exist in the calendar. Setting an earlier date moves the clock backwards, which
+    /// intervals handle as described in [`CatchUpPolicy`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ingame_clock::InGameClock;
+    /// let mut clock = InGameClock::with_start_datetime(2024, 1, 1, 0, 0, 0);
+    /// clock.set_datetime(2024, 3, 1, 6, 30, 0).unwrap();
+    /// assert_eq!(clock.format_datetime(None), "2024-03-01 06:30:00");
+    /// assert!(clock.set_datetime(2024, 2, 30, 0, 0, 0).is_err());
+    /// ```
+    pub fn set_datetime(&mut self, year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Result<(), DateError> {
+        self.elapsed_seconds = self.calendar.to_elapsed((year, month, day), (hour, minute, second), self.start_datetime)?;
+        Ok(())
+    }
+
     /// Gets the current NaiveDateTime based on elapsed time
     pub fn current_datetime(&self) -> NaiveDateTime {
-        let duration = Duration::milliseconds((self.elapsed_seconds * 1000.0) as i64);
+        let duration = Duration::milliseconds(((self.start_offset + self.elapsed_seconds) * 1000.0).floor() as i64);
         self.start_datetime + duration
     }
 
@@ -281,6 +760,30 @@ impl InGameClock {
         self.calendar.get_time(self.elapsed_seconds, self.start_datetime)
     }
 
+    /// Gets the current season of the calendar, if the calendar defines seasons
+    pub fn current_season(&self) -> Option<Season> {
+        let (_, month, _) = self.current_date();
+        let seasons = self.calendar.seasons();
+        Season::find(&seasons, month).map(|(index, _)| seasons[index].clone())
+    }
+
+    /// Gets the current day of the week as (index, name), see [`Calendar::get_weekday`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ingame_clock::InGameClock;
+    /// let clock = InGameClock::with_start_datetime(2024, 6, 15, 8, 30, 0);
+    /// assert_eq!(clock.current_weekday(), (5, "Saturday".to_string()));
+    /// ```
+    pub fn current_weekday(&self) -> (usize, String) {
+        self.calendar.get_weekday(self.elapsed_seconds, self.start_datetime)
+    }
+
+    /// Gets the current era as (name, year counted in the era), see [`Calendar::get_era`]
+    pub fn current_era(&self) -> Option<(String, i32)> {
+        self.calendar.get_era(self.elapsed_seconds, self.start_datetime)
+    }
+
     /// Formats the current date with an optional custom format string.
     ///
     /// If no format is provided, defaults to "YYYY-MM-DD" (%Y-%m-%d).
@@ -330,6 +833,9 @@ impl InGameClock {
     }
 
     /// Get the calendar used by this clock
+    ///
+    /// The calendar takes the elapsed seconds of this clock, so it already accounts for
+    /// a start set with [`InGameClock::with_calendar_start`].
     pub fn calendar(&self) -> &Arc<dyn Calendar> {
         &self.calendar
     }
@@ -352,24 +858,90 @@ fn check_intervals(
         return;
     }
 
+    let calendar = clock.calendar().as_ref();
+    let now = clock.elapsed_seconds;
+    
     for tracker in &mut trackers.trackers {
-        let interval_seconds = tracker.interval.as_seconds(clock.calendar().as_ref()) as f64;
-        
-        // Check how many times this interval has passed
-        let current_intervals = (clock.elapsed_seconds / interval_seconds).floor() as u64;
-        let previous_intervals = (tracker.last_trigger_seconds / interval_seconds).floor() as u64;
-        
-        // Fire events for each interval that passed
-        for _ in previous_intervals..current_intervals {
-            tracker.count += 1;
-            events.write(ClockIntervalEvent {
-                interval: tracker.interval,
-                count: tracker.count,
-            });
+        let interval = tracker.interval;
+        let alignment = tracker.settings.alignment;
+        let next_boundary = |after: f64| interval.next_boundary(after, alignment, calendar, clock.start_datetime);
+        let event = |boundary: f64, count: u64, missed: u64| ClockIntervalEvent {
+            interval,
+            count,
+            alignment,
+            elapsed_seconds: boundary,
+            date: calendar.get_date(boundary, clock.start_datetime),
+            time: calendar.get_time(boundary, clock.start_datetime),
+            lateness: now - boundary,
+            missed,
+        };
+        
+        // The clock moved backwards: drop pending boundaries and continue from the new time
+        if now < tracker.last_trigger_seconds {
+            tracker.last_trigger_seconds = now;
+            continue;
         }
         
-        tracker.last_trigger_seconds = clock.elapsed_seconds;
+        match tracker.settings.catch_up {
+            CatchUpPolicy::Coalesce => {
+                if let Some((passed, latest)) = count_boundaries(tracker, now, calendar, &next_boundary) {
+                    tracker.count += passed;
+                    events.write(event(latest, tracker.count, passed - 1));
+                }
+                tracker.last_trigger_seconds = now;
+            }
+            policy => {
+                let limit = match policy {
+                    CatchUpPolicy::Cap(limit) => limit.max(1),
+                    _ => u32::MAX,
+                };
+                
+                // Fire events for each interval boundary passed since the last check
+                let mut fired = 0;
+                let mut boundary = next_boundary(tracker.last_trigger_seconds);
+                while boundary <= now && fired < limit {
+                    tracker.count += 1;
+                    events.write(event(boundary, tracker.count, 0));
+                    tracker.last_trigger_seconds = boundary;
+                    fired += 1;
+                    boundary = next_boundary(boundary);
+                }
+                
+                // Carry boundaries over the cap to the next frame
+                if boundary > now {
+                    tracker.last_trigger_seconds = now;
+                }
+            }
+        }
+    }
+}
+
+/// Count the boundaries passed since the tracker was last checked, and find the latest one
+///
+/// Fixed-length intervals counted since the start are counted directly, so that large
+/// jumps of the clock do not have to visit every boundary.
+fn count_boundaries(
+    tracker: &IntervalTracker,
+    now: f64,
+    calendar: &dyn Calendar,
+    next_boundary: &impl Fn(f64) -> f64,
+) -> Option<(u64, f64)> {
+    let fixed_seconds = tracker.interval.as_seconds(calendar).filter(|seconds| *seconds > 0.0 && seconds.is_finite());
+    if let (Some(seconds), IntervalAlignment::SinceStart) = (fixed_seconds, tracker.settings.alignment) {
+        let current = (now / seconds).floor();
+        let passed = current - (tracker.last_trigger_seconds / seconds).floor();
+        return (passed > 0.0).then_some((passed as u64, current * seconds));
     }
+    
+    let mut passed = 0;
+    let mut latest = None;
+    let mut boundary = next_boundary(tracker.last_trigger_seconds);
+    while boundary <= now {
+        passed += 1;
+        latest = Some(boundary);
+        boundary = next_boundary(boundary);
+    }
+    latest.map(|latest| (passed, latest))
 }
 
 /// Commands extension trait for registering clock intervals
@@ -388,13 +960,155 @@ pub trait ClockCommands {
     ///     commands.register_clock_interval(ClockInterval::Custom(90));
     /// }
     /// ```
-    fn register_clock_interval(&mut self, interval: ClockInterval);
+    fn register_clock_interval(&mut self, interval: ClockInterval) -> IntervalHandle;
+
+    /// Register an interval with custom [`IntervalSettings`] to trigger clock events
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_ingame_clock::{ClockCommands, ClockInterval, IntervalAlignment, IntervalSettings};
+    /// fn setup(mut commands: Commands) {
+    ///     // Fire at midnight every day, regardless of the clock's start time
+    ///     commands.register_clock_interval_with(
+    ///         ClockInterval::Day,
+    ///         IntervalSettings::default().with_alignment(IntervalAlignment::AlignToCalendar),
+    ///     );
+    /// }
+    /// ```
+    fn register_clock_interval_with(&mut self, interval: ClockInterval, settings: IntervalSettings) -> IntervalHandle;
+
+    /// Release an interval handle returned by [`ClockCommands::register_clock_interval`]
+    ///
+    /// The interval keeps firing while other handles to it are still registered.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_ingame_clock::{ClockCommands, ClockInterval, IntervalHandle};
+    /// #[derive(Resource)]
+    /// struct HourlyEvents(IntervalHandle);
+    ///
+    /// fn setup(mut commands: Commands) {
+    ///     let handle = commands.register_clock_interval(ClockInterval::Hour);
+    ///     commands.insert_resource(HourlyEvents(handle));
+    /// }
+    ///
+    /// fn teardown(mut commands: Commands, hourly: Res<HourlyEvents>) {
+    ///     commands.unregister_clock_interval(hourly.0);
+    /// }
+    /// ```
+    fn unregister_clock_interval(&mut self, handle: IntervalHandle);
+
+    /// Remove every registered clock interval
+    fn clear_clock_intervals(&mut self);
+
+    /// Schedule a one-shot [`ClockAlarm`], returning an id to cancel it
+    ///
+    /// An alarm at a date or time that does not exist in the clock's calendar is not
+    /// scheduled, and its [`DateError`] is passed to Bevy's error handler.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_ingame_clock::{AlarmFired, ClockAlarm, ClockCommands};
+    /// fn setup(mut commands: Commands) {
+    ///     commands.schedule_clock_alarm(ClockAlarm::at_datetime(1024, 1, 3, 6, 0, 0).with_label("caravan"));
+    /// }
+    ///
+    /// fn on_alarm(mut alarms: MessageReader<AlarmFired>) {
+    ///     for alarm in alarms.read() {
+    ///         println!("Alarm {:?} fired", alarm.label);
+    ///     }
+    /// }
+    /// ```
+    fn schedule_clock_alarm(&mut self, alarm: ClockAlarm) -> AlarmId;
+
+    /// Cancel a scheduled alarm before it fires
+    fn cancel_clock_alarm(&mut self, id: AlarmId);
+
+    /// Register a recurring [`ClockSchedule`], returning an id to remove it
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_ingame_clock::{ClockCommands, ClockSchedule, InGameClock, ScheduleFired};
+    /// fn setup(mut commands: Commands, clock: Res<InGameClock>) {
+    ///     let schedule = ClockSchedule::parse("0 18 * * Sunday", clock.calendar().as_ref()).unwrap();
+    ///     commands.add_clock_schedule(schedule.with_label("market"));
+    /// }
+    ///
+    /// fn on_schedule(mut schedules: MessageReader<ScheduleFired>) {
+    ///     for occurrence in schedules.read() {
+    ///         println!("{:?} at {}", occurrence.label, occurrence.elapsed_seconds);
+    ///     }
+    /// }
+    /// ```
+    fn add_clock_schedule(&mut self, schedule: ClockSchedule) -> ScheduleId;
+
+    /// Remove a registered schedule
+    fn remove_clock_schedule(&mut self, id: ScheduleId);
+
+    /// Restore the clock and all registered intervals from a saved [`ClockSnapshot`]
+    fn restore_clock(&mut self, snapshot: ClockSnapshot);
 }
 
 impl ClockCommands for Commands<'_, '_> {
-    fn register_clock_interval(&mut self, interval: ClockInterval) {
+    fn register_clock_interval(&mut self, interval: ClockInterval) -> IntervalHandle {
+        self.register_clock_interval_with(interval, IntervalSettings::default())
+    }
+
+    fn register_clock_interval_with(&mut self, interval: ClockInterval, settings: IntervalSettings) -> IntervalHandle {
+        let handle = IntervalHandle::new(interval, settings);
         self.queue(move |world: &mut World| {
-            InGameClock::register_interval(world, interval);
+            InGameClock::register_interval_handle(world, handle);
+        });
+        handle
+    }
+
+    fn unregister_clock_interval(&mut self, handle: IntervalHandle) {
+        self.queue(move |world: &mut World| {
+            InGameClock::unregister_interval(world, handle);
+        });
+    }
+
+    fn clear_clock_intervals(&mut self) {
+        self.queue(|world: &mut World| {
+            InGameClock::clear_intervals(world);
+        });
+    }
+
+    fn schedule_clock_alarm(&mut self, alarm: ClockAlarm) -> AlarmId {
+        let id = AlarmId::new();
+        self.queue(move |world: &mut World| -> Result<(), DateError> {
+            InGameClock::schedule_alarm_with_id(world, id, alarm)
+        });
+        id
+    }
+
+    fn cancel_clock_alarm(&mut self, id: AlarmId) {
+        self.queue(move |world: &mut World| {
+            InGameClock::cancel_alarm(world, id);
+        });
+    }
+
+    fn add_clock_schedule(&mut self, schedule: ClockSchedule) -> ScheduleId {
+        let id = ScheduleId::new();
+        self.queue(move |world: &mut World| {
+            InGameClock::add_schedule_with_id(world, id, schedule);
+        });
+        id
+    }
+
+    fn remove_clock_schedule(&mut self, id: ScheduleId) {
+        self.queue(move |world: &mut World| {
+            InGameClock::remove_schedule(world, id);
+        });
+    }
+
+    fn restore_clock(&mut self, snapshot: ClockSnapshot) {
+        self.queue(move |world: &mut World| {
+            snapshot.restore(world);
         });
     }
 }
@@ -590,26 +1304,421 @@ mod tests {
             .epoch(Epoch::new("Test Epoch", 0))
             .build();
 
-        assert_eq!(ClockInterval::Second.as_seconds(&custom_calendar), 1);
-        assert_eq!(ClockInterval::Minute.as_seconds(&custom_calendar), 60);
-        assert_eq!(ClockInterval::Hour.as_seconds(&custom_calendar), 3600); // 60 * 60
-        assert_eq!(ClockInterval::Day.as_seconds(&custom_calendar), 72000); // 20 * 60 * 60
-        assert_eq!(ClockInterval::Week.as_seconds(&custom_calendar), 360000); // 72000 * 5
-        assert_eq!(ClockInterval::Custom(90).as_seconds(&custom_calendar), 90);
+        assert_eq!(ClockInterval::Second.as_seconds(&custom_calendar), Some(1.0));
+        assert_eq!(ClockInterval::Minute.as_seconds(&custom_calendar), Some(60.0));
+        assert_eq!(ClockInterval::Hour.as_seconds(&custom_calendar), Some(3600.0)); // 60 * 60
+        assert_eq!(ClockInterval::Day.as_seconds(&custom_calendar), Some(72000.0)); // 20 * 60 * 60
+        assert_eq!(ClockInterval::Week.as_seconds(&custom_calendar), Some(360000.0)); // 72000 * 5
+        assert_eq!(ClockInterval::Custom(90).as_seconds(&custom_calendar), Some(90.0));
+        
+        let long_minutes = CustomCalendar::builder()
+            .seconds_per_minute(100)
+            .month(Month::new("Month1", 20, 0))
+            .weekday("Day1")
+            .build();
+        assert_eq!(ClockInterval::Minute.as_seconds(&long_minutes), Some(100.0));
+        assert_eq!(ClockInterval::every(2, TimeUnit::Minute).as_seconds(&long_minutes), Some(200.0));
+        assert_eq!(ClockInterval::Hour.as_seconds(&long_minutes), Some(6000.0));
     }
     
     #[test]
     fn test_clock_interval_as_seconds() {
         let gregorian = GregorianCalendar;
-        assert_eq!(ClockInterval::Second.as_seconds(&gregorian), 1);
-        assert_eq!(ClockInterval::Minute.as_seconds(&gregorian), 60);
-        assert_eq!(ClockInterval::Hour.as_seconds(&gregorian), 3600);
-        assert_eq!(ClockInterval::Day.as_seconds(&gregorian), 86400);
-        assert_eq!(ClockInterval::Week.as_seconds(&gregorian), 604800);
-        assert_eq!(ClockInterval::Custom(90).as_seconds(&gregorian), 90);
+        assert_eq!(ClockInterval::Second.as_seconds(&gregorian), Some(1.0));
+        assert_eq!(ClockInterval::Minute.as_seconds(&gregorian), Some(60.0));
+        assert_eq!(ClockInterval::Hour.as_seconds(&gregorian), Some(3600.0));
+        assert_eq!(ClockInterval::Day.as_seconds(&gregorian), Some(86400.0));
+        assert_eq!(ClockInterval::Week.as_seconds(&gregorian), Some(604800.0));
+        assert_eq!(ClockInterval::Custom(90).as_seconds(&gregorian), Some(90.0));
+        assert_eq!(ClockInterval::Month.as_seconds(&gregorian), None);
+        assert_eq!(ClockInterval::every(0.25, TimeUnit::Second).as_seconds(&gregorian), Some(0.25));
+        assert_eq!(ClockInterval::every(400, TimeUnit::Day).as_seconds(&gregorian), Some(34560000.0));
     }
     
     
+    #[test]
+    fn test_register_interval_shares_tracker() {
+        let mut world = World::new();
+        world.init_resource::<ClockIntervalTrackers>();
+        
+        let first = InGameClock::register_interval(&mut world, ClockInterval::Hour);
+        let second = InGameClock::register_interval(&mut world, ClockInterval::Hour);
+        InGameClock::register_interval(&mut world, ClockInterval::Day);
+        assert_ne!(first, second);
+        assert_eq!(first.interval(), ClockInterval::Hour);
+        assert_eq!(world.resource::<ClockIntervalTrackers>().trackers.len(), 2);
+        
+        // The hour tracker stays alive until its last owner releases it
+        InGameClock::unregister_interval(&mut world, first);
+        assert_eq!(world.resource::<ClockIntervalTrackers>().trackers.len(), 2);
+        InGameClock::unregister_interval(&mut world, first);
+        assert_eq!(world.resource::<ClockIntervalTrackers>().trackers.len(), 2);
+        InGameClock::unregister_interval(&mut world, second);
+        let trackers = world.resource::<ClockIntervalTrackers>();
+        assert_eq!(trackers.trackers.len(), 1);
+        assert_eq!(trackers.trackers[0].interval, ClockInterval::Day);
+    }
+    
+    #[test]
+    fn test_clock_commands_unregister_and_clear() {
+        let mut world = World::new();
+        world.init_resource::<ClockIntervalTrackers>();
+        
+        let mut queue = bevy::ecs::world::CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let hour = commands.register_clock_interval(ClockInterval::Hour);
+        commands.register_clock_interval(ClockInterval::Day);
+        commands.register_clock_interval(ClockInterval::Week);
+        commands.unregister_clock_interval(hour);
+        queue.apply(&mut world);
+        assert_eq!(world.resource::<ClockIntervalTrackers>().trackers.len(), 2);
+        
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.clear_clock_intervals();
+        queue.apply(&mut world);
+        assert!(world.resource::<ClockIntervalTrackers>().trackers.is_empty());
+    }
+    
+    /// Set the clock to `elapsed_seconds`, run one update and return the fired intervals
+    fn advance_to(app: &mut App, elapsed_seconds: f64) -> Vec<ClockInterval> {
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = elapsed_seconds;
+        app.update();
+        app.world_mut()
+            .resource_mut::<Messages<ClockIntervalEvent>>()
+            .drain()
+            .map(|event| event.interval)
+            .collect()
+    }
+    
+    #[test]
+    fn test_with_calendar_start() {
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(20)
+            .month(Month::new("Frostmoon", 20, 1))
+            .month(Month::new("Bloomtide", 21, 0))
+            .weekday("Moonday")
+            .leap_years("# % 4 == 0")
+            .epoch(Epoch::new("Age of Magic", 1000))
+            .build();
+        let clock = InGameClock::new().with_calendar(calendar.clone());
+        let start = |date: (i32, u32, u32), time: (u32, u32, u32)| {
+            clock.clone().with_calendar_start(date.0, date.1, date.2, time.0, time.1, time.2)
+        };
+        
+        let mut clock = start((1247, 2, 12), (7, 0, 0)).unwrap();
+        assert_eq!(clock.current_date(), (1247, 2, 12));
+        assert_eq!(clock.current_time(), (7, 0, 0));
+        clock.elapsed_seconds = 13.0 * 3600.0;
+        assert_eq!(clock.current_date(), (1247, 2, 13));
+        assert_eq!(clock.format_time(None), "00:00:00");
+        
+        // Leap days only exist in leap years
+        assert!(start((1248, 1, 21), (0, 0, 0)).is_ok());
+        assert_eq!(start((1247, 1, 21), (0, 0, 0)).unwrap_err(), DateError::InvalidDate { year: 1247, month: 1, day: 21 });
+        assert!(start((1247, 2, 22), (0, 0, 0)).is_err());
+        assert!(start((1247, 3, 1), (0, 0, 0)).is_err());
+        assert!(start((1247, 1, 0), (0, 0, 0)).is_err());
+        assert_eq!(start((1247, 1, 1), (20, 0, 0)).unwrap_err(), DateError::InvalidTime { hour: 20, minute: 0, second: 0 });
+        assert!(start((1247, 1, 1), (7, 60, 0)).is_err());
+        
+        // Gregorian clocks are started through their start datetime
+        let clock = InGameClock::new().with_calendar_start(2024, 2, 29, 12, 0, 0).unwrap();
+        assert_eq!(clock.format_datetime(None), "2024-02-29 12:00:00");
+        assert_eq!(clock.start_offset(), 0.0);
+        assert!(InGameClock::new().with_calendar_start(2023, 2, 29, 12, 0, 0).is_err());
+    }
+    
+    #[test]
+    fn test_current_weekday() {
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Frostmoon", 10, 0))
+            .weekdays(vec!["Moonday".to_string(), "Fireday".to_string(), "Starday".to_string()])
+            .epoch_weekday(1)
+            .epoch(Epoch::new("Age of Magic", 1000))
+            .build();
+        // 1001-01-05 is 14 days after the epoch, which started on a Fireday
+        let mut clock = InGameClock::new()
+            .with_calendar(calendar)
+            .with_calendar_start(1001, 1, 5, 12, 0, 0)
+            .unwrap();
+        assert_eq!(clock.current_weekday(), (0, "Moonday".to_string()));
+        assert_eq!(clock.now().weekday_name(), "Moonday");
+        
+        // Going back before the start of the clock
+        clock.elapsed_seconds = -86400.0;
+        assert_eq!(clock.current_weekday(), (2, "Starday".to_string()));
+    }
+    
+    #[test]
+    fn test_calendar_start_aligns_intervals() {
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(10)
+            .month(Month::new("Frostmoon", 20, 0))
+            .weekday("Moonday")
+            .epoch(Epoch::new("Age of Magic", 1000))
+            .build();
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin)
+            .init_resource::<Time>()
+            .insert_resource(InGameClock::new().with_calendar(calendar).with_calendar_start(1003, 1, 20, 7, 0, 0).unwrap());
+        let aligned = IntervalSettings::default().with_alignment(IntervalAlignment::AlignToCalendar);
+        InGameClock::register_interval_with(app.world_mut(), ClockInterval::Day, aligned);
+        InGameClock::register_interval(app.world_mut(), ClockInterval::Year);
+        
+        // The first day and year end three hours after the start
+        assert!(advance_to(&mut app, 3.0 * 3600.0 - 1.0).is_empty());
+        assert_eq!(advance_to(&mut app, 3.0 * 3600.0), vec![ClockInterval::Day, ClockInterval::Year]);
+        assert_eq!(app.world().resource::<InGameClock>().current_date(), (1004, 1, 1));
+    }
+    
+    #[test]
+    fn test_fractional_intervals() {
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin)
+            .init_resource::<Time>()
+            .insert_resource(InGameClock::with_start_datetime(2024, 1, 1, 0, 0, 0));
+        let tenth = ClockInterval::every(0.1, TimeUnit::Second);
+        let first = InGameClock::register_interval(app.world_mut(), tenth);
+        let second = InGameClock::register_interval(app.world_mut(), ClockInterval::every(0.1, TimeUnit::Second));
+        assert_eq!(first.interval(), second.interval());
+        assert_eq!(app.world().resource::<ClockIntervalTrackers>().trackers.len(), 1);
+        InGameClock::register_interval(app.world_mut(), ClockInterval::every(0, TimeUnit::Second));
+        
+        // Inexact boundaries such as 0.30000000000000004 are neither skipped nor repeated
+        let mut fired = 0;
+        for step in 1..=40 {
+            fired += advance_to(&mut app, step as f64 * 0.025).len();
+        }
+        assert_eq!(fired, 10);
+        assert_eq!(advance_to(&mut app, 2.0), vec![tenth; 10]);
+    }
+    
+    #[test]
+    fn test_interval_equality() {
+        let zero = ClockInterval::every(0, TimeUnit::Second);
+        assert_eq!(ClockInterval::every(-0.0, TimeUnit::Second), zero);
+        assert_eq!(ClockInterval::every(-2, TimeUnit::Second), zero);
+        assert_eq!(ClockInterval::every(f64::NAN, TimeUnit::Second), zero);
+        let nan = ClockInterval::Every(f64::NAN, TimeUnit::Second);
+        assert_eq!(nan, nan);
+        assert_ne!(ClockInterval::Every(0.0, TimeUnit::Second), ClockInterval::Every(-0.0, TimeUnit::Second));
+        
+        // Handles of intervals that never fire can still be released
+        let mut world = World::new();
+        world.init_resource::<ClockIntervalTrackers>();
+        let handle = InGameClock::register_interval(&mut world, nan);
+        InGameClock::unregister_interval(&mut world, handle);
+        assert!(world.resource::<ClockIntervalTrackers>().trackers.is_empty());
+    }
+    
+    #[test]
+    fn test_calendar_aligned_intervals() {
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin)
+            .init_resource::<Time>()
+            .insert_resource(InGameClock::with_start_datetime(2024, 1, 31, 12, 0, 0));
+        InGameClock::register_interval(app.world_mut(), ClockInterval::Month);
+        InGameClock::register_interval(app.world_mut(), ClockInterval::Year);
+        InGameClock::register_interval(app.world_mut(), ClockInterval::Season);
+        let day = 86400.0;
+        
+        // Still January
+        assert!(advance_to(&mut app, 0.4 * day).is_empty());
+        // February 1st, 00:00 (12 hours after the start)
+        assert_eq!(advance_to(&mut app, 0.5 * day), vec![ClockInterval::Month]);
+        // February has 29 days in 2024, so March 1st is 29.5 days after the start
+        assert!(advance_to(&mut app, 29.4 * day).is_empty());
+        assert_eq!(advance_to(&mut app, 29.5 * day), vec![ClockInterval::Month, ClockInterval::Season]);
+        // Jump to 2025-01-01: ten month boundaries, one year and three season boundaries
+        let fired = advance_to(&mut app, 335.5 * day);
+        assert_eq!(fired.iter().filter(|i| **i == ClockInterval::Month).count(), 10);
+        assert_eq!(fired.iter().filter(|i| **i == ClockInterval::Year).count(), 1);
+        assert_eq!(fired.iter().filter(|i| **i == ClockInterval::Season).count(), 3);
+        assert_eq!(app.world().resource::<InGameClock>().current_date(), (2025, 1, 1));
+    }
+    
+    #[test]
+    fn test_custom_calendar_month_boundaries() {
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(10)
+            .month(Month::new("Short", 3, 2))
+            .month(Month::new("Long", 10, 0))
+            .month(Month::new("Last", 7, 0))
+            .weekday("Day1")
+            .leap_years("# % 2 == 0")
+            .epoch(Epoch::new("Test Epoch", 1000))
+            .season(Season::new("Dry", 1))
+            .season(Season::new("Wet", 3))
+            .build();
+        let seconds_per_day = calendar.seconds_per_day() as f64;
+        let start = NaiveDateTime::default();
+        
+        // Year 1000 is a leap year, so the first month has 5 days
+        assert_eq!(ClockInterval::Month.next_boundary(0.0, IntervalAlignment::SinceStart, &calendar, start), 5.0 * seconds_per_day);
+        assert_eq!(ClockInterval::Month.next_boundary(5.0 * seconds_per_day, IntervalAlignment::SinceStart, &calendar, start), 15.0 * seconds_per_day);
+        assert_eq!(ClockInterval::Season.next_boundary(0.0, IntervalAlignment::SinceStart, &calendar, start), 15.0 * seconds_per_day);
+        assert_eq!(ClockInterval::Year.next_boundary(0.0, IntervalAlignment::SinceStart, &calendar, start), 22.0 * seconds_per_day);
+        // Year 1001 is not a leap year, so its first month has 3 days
+        assert_eq!(ClockInterval::Month.next_boundary(22.0 * seconds_per_day, IntervalAlignment::SinceStart, &calendar, start), 25.0 * seconds_per_day);
+        
+        let clock = InGameClock::new().with_calendar(calendar);
+        assert_eq!(clock.current_season().unwrap().name, "Dry");
+    }
+    
+    #[test]
+    fn test_aligned_intervals() {
+        // 2024-01-03 is a Wednesday
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin)
+            .init_resource::<Time>()
+            .insert_resource(InGameClock::with_start_datetime(2024, 1, 3, 8, 30, 0));
+        let aligned = IntervalSettings::default().with_alignment(IntervalAlignment::AlignToCalendar);
+        InGameClock::register_interval(app.world_mut(), ClockInterval::Day);
+        InGameClock::register_interval_with(app.world_mut(), ClockInterval::Day, aligned);
+        InGameClock::register_interval_with(app.world_mut(), ClockInterval::Hour, aligned);
+        InGameClock::register_interval_with(app.world_mut(), ClockInterval::Week, aligned);
+        assert_eq!(app.world().resource::<ClockIntervalTrackers>().trackers.len(), 4);
+        
+        // 09:00
+        assert_eq!(advance_to(&mut app, 1800.0), vec![ClockInterval::Hour]);
+        // Midnight fires the aligned day, but not the one counted since 08:30
+        let fired = advance_to(&mut app, 15.5 * 3600.0);
+        assert_eq!(fired.len(), 16);
+        assert_eq!(fired.iter().filter(|i| **i == ClockInterval::Day).count(), 1);
+        assert_eq!(app.world().resource::<InGameClock>().format_time(None), "00:00:00");
+        // 08:30 on the next day fires the day counted since the start
+        assert_eq!(advance_to(&mut app, 86400.0).iter().filter(|i| **i == ClockInterval::Day).count(), 1);
+        // The aligned week starts on Monday, 2024-01-08
+        let monday = (4.0 * 24.0 + 15.5) * 3600.0;
+        assert!(!advance_to(&mut app, monday - 1.0).contains(&ClockInterval::Week));
+        assert!(advance_to(&mut app, monday).contains(&ClockInterval::Week));
+    }
+    
+    #[test]
+    fn test_aligned_week_with_days_outside_week() {
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Month1", 6, 0))
+            .month(Month::new("Month2", 6, 0))
+            .weekdays(vec!["Day1".to_string(), "Day2".to_string(), "Day3".to_string()])
+            .intercalary_day(IntercalaryDay::new("Festival", 1, false).outside_week())
+            .build();
+        let start_datetime = NaiveDateTime::default();
+        let next_week = |after: f64| {
+            ClockInterval::Week.next_boundary(after * 86400.0, IntervalAlignment::AlignToCalendar, &calendar, start_datetime) / 86400.0
+        };
+        assert_eq!(next_week(0.0), 3.0);
+        // The week of days 3 to 5 lasts until the end of the festival on day 6
+        assert_eq!(next_week(3.5), 7.0);
+        assert_eq!(next_week(6.5), 7.0);
+        assert_eq!(next_week(7.0), 10.0);
+        assert_eq!(next_week(12.5), 13.0);
+    }
+    
+    #[test]
+    fn test_interval_event_boundary_payload() {
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin)
+            .init_resource::<Time>()
+            .insert_resource(InGameClock::with_start_datetime(2024, 1, 1, 22, 30, 0));
+        InGameClock::register_interval(app.world_mut(), ClockInterval::Hour);
+        
+        // Three hourly boundaries pass in a single frame
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = 3.25 * 3600.0;
+        app.update();
+        let events: Vec<ClockIntervalEvent> = app
+            .world_mut()
+            .resource_mut::<Messages<ClockIntervalEvent>>()
+            .drain()
+            .collect();
+        
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].elapsed_seconds, 3600.0);
+        assert_eq!(events[0].date, (2024, 1, 1));
+        assert_eq!(events[0].time, (23, 30, 0));
+        assert_eq!(events[0].lateness, 2.25 * 3600.0);
+        assert_eq!(events[2].elapsed_seconds, 3.0 * 3600.0);
+        assert_eq!(events[2].date, (2024, 1, 2));
+        assert_eq!(events[2].time, (1, 30, 0));
+        assert_eq!(events[2].lateness, 0.25 * 3600.0);
+    }
+    
+    /// Set the clock to `elapsed_seconds`, run one update and return the fired events
+    fn advance_events(app: &mut App, elapsed_seconds: f64) -> Vec<ClockIntervalEvent> {
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = elapsed_seconds;
+        app.update();
+        app.world_mut()
+            .resource_mut::<Messages<ClockIntervalEvent>>()
+            .drain()
+            .collect()
+    }
+    
+    #[test]
+    fn test_catch_up_policies() {
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin)
+            .init_resource::<Time>()
+            .insert_resource(InGameClock::with_start_datetime(2024, 1, 1, 0, 0, 0));
+        let capped = IntervalSettings::default().with_catch_up(CatchUpPolicy::Cap(2));
+        let coalesced = IntervalSettings::default().with_catch_up(CatchUpPolicy::Coalesce);
+        let capped_at_zero = IntervalSettings::default().with_catch_up(CatchUpPolicy::Cap(0));
+        InGameClock::register_interval_with(app.world_mut(), ClockInterval::Second, capped);
+        InGameClock::register_interval_with(app.world_mut(), ClockInterval::Minute, coalesced);
+        InGameClock::register_interval_with(app.world_mut(), ClockInterval::Hour, capped_at_zero);
+        let fired = |events: &[ClockIntervalEvent], interval: ClockInterval| -> Vec<(u64, f64, u64)> {
+            events
+                .iter()
+                .filter(|event| event.interval == interval)
+                .map(|event| (event.count, event.elapsed_seconds, event.missed))
+                .collect()
+        };
+        
+        // A whole hour passes in a single frame
+        let events = advance_events(&mut app, 3600.0);
+        assert_eq!(fired(&events, ClockInterval::Second), vec![(1, 1.0, 0), (2, 2.0, 0)]);
+        assert_eq!(fired(&events, ClockInterval::Minute), vec![(60, 3600.0, 59)]);
+        assert_eq!(fired(&events, ClockInterval::Hour), vec![(1, 3600.0, 0)]);
+        
+        // Capped boundaries are carried over to the next frames
+        let events = advance_events(&mut app, 3600.0);
+        assert_eq!(fired(&events, ClockInterval::Second), vec![(3, 3.0, 0), (4, 4.0, 0)]);
+        assert!(fired(&events, ClockInterval::Minute).is_empty());
+        
+        // Moving backwards drops pending boundaries without firing
+        assert!(advance_events(&mut app, 0.0).is_empty());
+        let events = advance_events(&mut app, 60.0);
+        assert_eq!(fired(&events, ClockInterval::Second), vec![(5, 1.0, 0), (6, 2.0, 0)]);
+        assert_eq!(fired(&events, ClockInterval::Minute), vec![(61, 60.0, 0)]);
+    }
+    
+    #[test]
+    fn test_aligned_custom_interval_restarts_each_day() {
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(2)
+            .month(Month::new("Month1", 30, 0))
+            .weekday("Day1")
+            .build();
+        let start = NaiveDateTime::default();
+        let aligned = IntervalAlignment::AlignToCalendar;
+        let interval = ClockInterval::Custom(5000);
+        
+        // A day has 7200 seconds, so the second boundary of each day is midnight
+        assert_eq!(interval.next_boundary(0.0, aligned, &calendar, start), 5000.0);
+        assert_eq!(interval.next_boundary(5000.0, aligned, &calendar, start), 7200.0);
+        assert_eq!(interval.next_boundary(7200.0, aligned, &calendar, start), 12200.0);
+        assert_eq!(interval.next_boundary(7200.0, IntervalAlignment::SinceStart, &calendar, start), 10000.0);
+    }
+    
+    #[test]
+    fn test_season_interval_without_seasons() {
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Month1", 30, 0))
+            .weekday("Day1")
+            .build();
+        let start = NaiveDateTime::default();
+        assert_eq!(ClockInterval::Season.next_boundary(0.0, IntervalAlignment::SinceStart, &calendar, start), f64::INFINITY);
+        assert!(InGameClock::new().with_calendar(calendar).current_season().is_none());
+    }
+    
     #[test]
     fn test_custom_calendar_builder_integration_with_clock() {
         // Test that builder-created calendar works with InGameClock
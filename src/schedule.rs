@@ -0,0 +1,540 @@
+//! Cron-like recurring schedules evaluated against the clock's calendar.
+
+use crate::{Calendar, InGameClock};
+use bevy::prelude::*;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Source of unique ids for registered schedules
+pub(crate) static NEXT_SCHEDULE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Number of days searched by [`ClockSchedule::next_occurrence`] before giving up
+///
+/// Registered schedules resume the search from there once the clock reaches that day.
+const MAX_SEARCH_DAYS: u32 = 10_000;
+
+/// Identifier of a registered schedule, used to remove it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ScheduleId(pub(crate) u64);
+
+impl ScheduleId {
+    pub(crate) fn new() -> Self {
+        Self(NEXT_SCHEDULE_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Error returned when a schedule expression cannot be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleError {
+    /// The expression does not have exactly five fields
+    FieldCount(usize),
+    /// A field contains something that is not a number, name, range, list or step
+    InvalidField { field: &'static str, value: String },
+    /// A month or weekday name does not exist in the calendar
+    UnknownName { field: &'static str, name: String },
+    /// A value is outside the range `min..=max` of the field in the calendar
+    OutOfRange { field: &'static str, value: u32, min: u32, max: u32 },
+}
+
+impl fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleError::FieldCount(count) => {
+                write!(f, "expected 5 fields (minute hour day month weekday), found {}", count)
+            }
+            ScheduleError::InvalidField { field, value } => write!(f, "invalid {} field: '{}'", field, value),
+            ScheduleError::UnknownName { field, name } => write!(f, "unknown {} name: '{}'", field, name),
+            ScheduleError::OutOfRange { field, value, min, max } => {
+                write!(f, "{} value {} is out of range {}-{}", field, value, min, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScheduleError {}
+
+/// Range of values `start..=end` matching every `step`th value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct FieldRange {
+    start: u32,
+    end: u32,
+    step: u32,
+}
+
+/// One field of a schedule, matching any value in one of its ranges
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ScheduleField(Vec<FieldRange>);
+
+impl ScheduleField {
+    /// Parse a field such as `*`, `5`, `1-5`, `*/3`, `6-18/3` or `Moonday,Starday`
+    ///
+    /// `min..=max` are the values of the field in the calendar, with `min` used for `*`, and
+    /// `names` are resolved to their 1-based position.
+    fn parse(field: &'static str, value: &str, min: u32, max: u32, names: &[String]) -> Result<Self, ScheduleError> {
+        let invalid = || ScheduleError::InvalidField {
+            field,
+            value: value.to_string(),
+        };
+        let parse_value = |token: &str| -> Result<u32, ScheduleError> {
+            if let Ok(number) = token.parse::<u32>() {
+                if number < min || number > max {
+                    return Err(ScheduleError::OutOfRange { field, value: number, min, max });
+                }
+                return Ok(number);
+            }
+            names
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(token))
+                .map(|index| index as u32 + 1)
+                .ok_or_else(|| ScheduleError::UnknownName {
+                    field,
+                    name: token.to_string(),
+                })
+        };
+
+        let mut ranges = Vec::new();
+        for item in value.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, step.parse::<u32>().ok().filter(|step| *step > 0).ok_or_else(invalid)?),
+                None => (item, 1),
+            };
+            let (start, end) = if range == "*" {
+                (min, u32::MAX)
+            } else if let Some((start, end)) = range.split_once('-') {
+                (parse_value(start)?, parse_value(end)?)
+            } else {
+                let start = parse_value(range)?;
+                // `5/10` means every 10th value starting at 5
+                (start, if step > 1 { u32::MAX } else { start })
+            };
+            if range.is_empty() || start > end {
+                return Err(invalid());
+            }
+            ranges.push(FieldRange { start, end, step });
+        }
+        Ok(Self(ranges))
+    }
+
+    fn matches(&self, value: u32) -> bool {
+        self.0
+            .iter()
+            .any(|range| value >= range.start && value <= range.end && (value - range.start).is_multiple_of(range.step))
+    }
+}
+
+/// A recurring schedule, similar to a cron expression, evaluated against the clock's calendar
+///
+/// The expression has five whitespace-separated fields:
+///
+/// | Field   | Values                                              |
+/// |---------|-----------------------------------------------------|
+/// | minute  | `0` to minutes per hour - 1                         |
+/// | hour    | `0` to hours per day - 1                            |
+/// | day     | `1` to the length of the longest month              |
+/// | month   | `1` to the number of months, or a month name         |
+/// | weekday | `1` to the number of weekdays, or a weekday name     |
+///
+/// Each field is `*` (any value), a value, a range `a-b`, a step `*/n` or `a-b/n`, or a
+/// comma-separated list of these. Values outside the calendar's range are rejected, and
+/// names are resolved against [`Calendar::month_names`] and [`Calendar::weekday_names`],
+/// ignoring case. All fields must match for the schedule to fire, which happens at second 0
+/// of a matching minute.
+///
+/// # Examples
+/// ```
+/// # use bevy_ingame_clock::{ClockSchedule, CustomCalendar, GregorianCalendar, Month};
+/// // Every Sunday at 18:00
+/// let schedule = ClockSchedule::parse("0 18 * * Sunday", &GregorianCalendar).unwrap();
+///
+/// // The 1st of every month at dawn, and every 3rd hour between 06 and 18
+/// let schedule = ClockSchedule::parse("0 6 1 * *", &GregorianCalendar).unwrap();
+/// let schedule = ClockSchedule::parse("0 6-18/3 * * *", &GregorianCalendar).unwrap();
+///
+/// // Every Starday at 18:00 in a fantasy calendar
+/// let calendar = CustomCalendar::builder()
+///     .month(Month::new("Frostmoon", 30, 0))
+///     .weekday("Moonday")
+///     .weekday("Starday")
+///     .build();
+/// let schedule = ClockSchedule::parse("0 18 * * Starday", &calendar).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClockSchedule {
+    expression: String,
+    minute: ScheduleField,
+    hour: ScheduleField,
+    day: ScheduleField,
+    month: ScheduleField,
+    weekday: ScheduleField,
+    /// User-defined payload passed along in [`ScheduleFired`]
+    pub label: Option<String>,
+}
+
+impl ClockSchedule {
+    /// Parse a schedule expression, resolving month and weekday names with `calendar`
+    pub fn parse(expression: &str, calendar: &dyn Calendar) -> Result<Self, ScheduleError> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(ScheduleError::FieldCount(fields.len()));
+        }
+
+        let seconds_per_hour = calendar.seconds_per_hour();
+        let hours_per_day = calendar.seconds_per_day() / seconds_per_hour;
+        let minutes_per_hour = seconds_per_hour / calendar.seconds_per_minute();
+        let month_names = calendar.month_names();
+        let weekday_names = calendar.weekday_names();
+
+        Ok(Self {
+            expression: expression.to_string(),
+            minute: ScheduleField::parse("minute", fields[0], 0, minutes_per_hour - 1, &[])?,
+            hour: ScheduleField::parse("hour", fields[1], 0, hours_per_day - 1, &[])?,
+            day: ScheduleField::parse("day", fields[2], 1, calendar.max_days_in_month(), &[])?,
+            month: ScheduleField::parse("month", fields[3], 1, month_names.len() as u32, &month_names)?,
+            weekday: ScheduleField::parse("weekday", fields[4], 1, weekday_names.len() as u32, &weekday_names)?,
+            label: None,
+        })
+    }
+
+    /// Sets the label passed along when the schedule fires
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Get the expression this schedule was parsed from
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Get the elapsed seconds of the first occurrence strictly after `after`
+    ///
+    /// Returns `None` if the schedule does not match within the next 10000 days, such as a
+    /// leap day falling on a given weekday, which can be decades apart. Registered schedules
+    /// resume the search as the clock advances, so such occurrences still fire.
+    pub fn next_occurrence(&self, after: f64, calendar: &dyn Calendar, start_datetime: NaiveDateTime) -> Option<f64> {
+        match self.search(after, calendar.day_start(after, start_datetime), calendar, start_datetime) {
+            Upcoming::At(elapsed) => Some(elapsed),
+            Upcoming::NotBefore(_) => None,
+        }
+    }
+
+    /// Search [`MAX_SEARCH_DAYS`] days from the day starting at `day_start` for the first
+    /// occurrence strictly after `after`
+    fn search(&self, after: f64, day_start: f64, calendar: &dyn Calendar, start_datetime: NaiveDateTime) -> Upcoming {
+        let seconds_per_day = calendar.seconds_per_day() as f64;
+        let seconds_per_hour = calendar.seconds_per_hour();
+        let hours_per_day = calendar.seconds_per_day() / seconds_per_hour;
+        let seconds_per_minute = calendar.seconds_per_minute();
+        let minutes_per_hour = seconds_per_hour / seconds_per_minute;
+
+        let mut day_start = day_start;
+        for _ in 0..MAX_SEARCH_DAYS {
+            let (_, month, day) = calendar.get_date(day_start, start_datetime);
+            let weekday = calendar.get_weekday(day_start, start_datetime).0 as u32 + 1;
+
+            if self.day.matches(day) && self.month.matches(month) && self.weekday.matches(weekday) {
+                for hour in (0..hours_per_day).filter(|hour| self.hour.matches(*hour)) {
+                    for minute in (0..minutes_per_hour).filter(|minute| self.minute.matches(*minute)) {
+                        let elapsed = day_start + (hour * seconds_per_hour + minute * seconds_per_minute) as f64;
+                        if elapsed > after {
+                            return Upcoming::At(elapsed);
+                        }
+                    }
+                }
+            }
+            day_start += seconds_per_day;
+        }
+        Upcoming::NotBefore(day_start)
+    }
+}
+
+/// Result of searching a schedule for its next occurrence
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Upcoming {
+    /// The elapsed seconds of the next occurrence
+    At(f64),
+    /// No occurrence before the day starting at these elapsed seconds, where the search stopped
+    NotBefore(f64),
+}
+
+/// Message sent when a registered [`ClockSchedule`] matches
+#[derive(Message, Debug, Clone)]
+pub struct ScheduleFired {
+    /// The id returned when the schedule was registered
+    pub id: ScheduleId,
+    /// The label of the schedule
+    pub label: Option<String>,
+    /// The elapsed in-game seconds of the occurrence
+    pub elapsed_seconds: f64,
+}
+
+pub(crate) struct ScheduleEntry {
+    pub(crate) id: ScheduleId,
+    pub(crate) schedule: ClockSchedule,
+    /// The elapsed seconds up to which occurrences have been fired, `None` until first checked
+    last_checked: Option<f64>,
+    /// Cached result of the search for the next occurrence after `last_checked`
+    next: Option<Upcoming>,
+}
+
+impl ScheduleEntry {
+    pub(crate) fn new(id: ScheduleId, schedule: ClockSchedule) -> Self {
+        Self {
+            id,
+            schedule,
+            last_checked: None,
+            next: None,
+        }
+    }
+}
+
+/// Resource holding all registered schedules
+#[derive(Resource, Default)]
+pub(crate) struct ClockSchedules {
+    pub(crate) schedules: Vec<ScheduleEntry>,
+}
+
+impl InGameClock {
+    /// Register a recurring schedule
+    ///
+    /// Only occurrences after the time the schedule is first checked fire.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ingame_clock::{ClockSchedule, GregorianCalendar, InGameClock, InGameClockPlugin};
+    /// let mut app = App::new();
+    /// app.add_plugins(InGameClockPlugin);
+    ///
+    /// let schedule = ClockSchedule::parse("0 18 * * Sunday", &GregorianCalendar).unwrap();
+    /// let id = InGameClock::add_schedule(app.world_mut(), schedule);
+    /// InGameClock::remove_schedule(app.world_mut(), id);
+    /// ```
+    pub fn add_schedule(world: &mut World, schedule: ClockSchedule) -> ScheduleId {
+        let id = ScheduleId::new();
+        Self::add_schedule_with_id(world, id, schedule);
+        id
+    }
+
+    pub(crate) fn add_schedule_with_id(world: &mut World, id: ScheduleId, schedule: ClockSchedule) {
+        world
+            .resource_mut::<ClockSchedules>()
+            .schedules
+            .push(ScheduleEntry::new(id, schedule));
+    }
+
+    /// Remove a registered schedule
+    pub fn remove_schedule(world: &mut World, id: ScheduleId) {
+        world.resource_mut::<ClockSchedules>().schedules.retain(|entry| entry.id != id);
+    }
+}
+
+/// System that fires every schedule occurrence passed since the last check
+pub(crate) fn check_schedules(
+    clock: Res<InGameClock>,
+    mut schedules: ResMut<ClockSchedules>,
+    mut events: MessageWriter<ScheduleFired>,
+) {
+    if clock.paused {
+        return;
+    }
+
+    let calendar = clock.calendar().as_ref();
+    let now = clock.elapsed_seconds;
+
+    for entry in &mut schedules.schedules {
+        // Start counting from now, and again whenever the clock moved backwards
+        let last_checked = match entry.last_checked {
+            Some(last_checked) if last_checked <= now => last_checked,
+            _ => {
+                entry.next = None;
+                now
+            }
+        };
+
+        let search = |after: f64, day_start: f64| entry.schedule.search(after, day_start, calendar, clock.start_datetime);
+        let mut next = entry
+            .next
+            .unwrap_or_else(|| search(last_checked, calendar.day_start(last_checked, clock.start_datetime)));
+        loop {
+            match next {
+                Upcoming::At(occurrence) if occurrence <= now => {
+                    events.write(ScheduleFired {
+                        id: entry.id,
+                        label: entry.schedule.label.clone(),
+                        elapsed_seconds: occurrence,
+                    });
+                    next = search(occurrence, calendar.day_start(occurrence, clock.start_datetime));
+                }
+                // Resume a search that gave up once the clock reaches the day it stopped at,
+                // instead of searching again from now on every frame
+                Upcoming::NotBefore(day_start) if day_start <= now => next = search(last_checked, day_start),
+                _ => break,
+            }
+        }
+
+        entry.last_checked = Some(now);
+        entry.next = Some(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomCalendar, Epoch, GregorianCalendar, InGameClockPlugin, Month};
+
+    fn start() -> NaiveDateTime {
+        InGameClock::with_start_datetime(2024, 1, 1, 0, 0, 0).start_datetime
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let calendar = GregorianCalendar;
+        assert_eq!(ClockSchedule::parse("0 18 * *", &calendar), Err(ScheduleError::FieldCount(4)));
+        assert!(matches!(
+            ClockSchedule::parse("0 18-6 * * *", &calendar),
+            Err(ScheduleError::InvalidField { field: "hour", .. })
+        ));
+        assert!(matches!(
+            ClockSchedule::parse("*/0 * * * *", &calendar),
+            Err(ScheduleError::InvalidField { field: "minute", .. })
+        ));
+        assert_eq!(
+            ClockSchedule::parse("75 18 * * *", &calendar),
+            Err(ScheduleError::OutOfRange {
+                field: "minute",
+                value: 75,
+                min: 0,
+                max: 59
+            })
+        );
+        assert!(matches!(
+            ClockSchedule::parse("0 0 1 1-13 *", &calendar),
+            Err(ScheduleError::OutOfRange { field: "month", value: 13, .. })
+        ));
+        assert!(matches!(
+            ClockSchedule::parse("0 0 0 * *", &calendar),
+            Err(ScheduleError::OutOfRange { field: "day", value: 0, .. })
+        ));
+        assert_eq!(
+            ClockSchedule::parse("0 18 * * Starday", &calendar),
+            Err(ScheduleError::UnknownName {
+                field: "weekday",
+                name: "Starday".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_next_occurrence_gregorian() {
+        let calendar = GregorianCalendar;
+        let day = 86400.0;
+
+        // 2024-01-01 is a Monday, so the first Sunday is the 7th
+        let sunday_evening = ClockSchedule::parse("0 18 * * Sunday", &calendar).unwrap();
+        assert_eq!(sunday_evening.next_occurrence(0.0, &calendar, start()), Some(6.0 * day + 18.0 * 3600.0));
+
+        let first_of_month = ClockSchedule::parse("0 6 1 february *", &calendar).unwrap();
+        assert_eq!(first_of_month.next_occurrence(0.0, &calendar, start()), Some(31.0 * day + 6.0 * 3600.0));
+
+        // Every 3rd hour between 06 and 18 is 06, 09, 12, 15 and 18
+        let every_third_hour = ClockSchedule::parse("0 6-18/3 * * *", &calendar).unwrap();
+        let mut hours = Vec::new();
+        let mut after = 0.0;
+        while let Some(next) = every_third_hour.next_occurrence(after, &calendar, start()) {
+            if next >= day {
+                break;
+            }
+            hours.push(next / 3600.0);
+            after = next;
+        }
+        assert_eq!(hours, vec![6.0, 9.0, 12.0, 15.0, 18.0]);
+
+        let never = ClockSchedule::parse("0 0 31 2 *", &calendar).unwrap();
+        assert_eq!(never.next_occurrence(0.0, &calendar, start()), None);
+    }
+
+    #[test]
+    fn test_next_occurrence_custom_calendar() {
+        let calendar = CustomCalendar::builder()
+            .minutes_per_hour(20)
+            .hours_per_day(20)
+            .month(Month::new("Frostmoon", 20, 0))
+            .month(Month::new("Thawmoon", 21, 0))
+            .weekday("Moonday")
+            .weekday("Fireday")
+            .weekday("Starday")
+            .epoch(Epoch::new("Age of Magic", 1000))
+            .build();
+        let seconds_per_day = calendar.seconds_per_day() as f64;
+
+        let schedule = ClockSchedule::parse("10 18 * thawmoon Moonday", &calendar).unwrap();
+        // Thawmoon starts on day 20, which is a Starday, so the first Moonday is day 21
+        assert_eq!(
+            schedule.next_occurrence(0.0, &calendar, start()),
+            Some(21.0 * seconds_per_day + 18.0 * 1200.0 + 600.0)
+        );
+    }
+
+    #[test]
+    fn test_schedule_fires_messages() {
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin)
+            .init_resource::<Time>()
+            .insert_resource(InGameClock::with_start_datetime(2024, 1, 1, 0, 0, 0));
+        let schedule = ClockSchedule::parse("0 */6 * * *", &GregorianCalendar).unwrap().with_label("watch");
+        let id = InGameClock::add_schedule(app.world_mut(), schedule);
+        app.update();
+
+        // Jump a full day ahead: 06, 12, 18 and 00 have passed
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = 86400.0;
+        app.update();
+        let fired: Vec<ScheduleFired> = app.world_mut().resource_mut::<Messages<ScheduleFired>>().drain().collect();
+        assert_eq!(fired.len(), 4);
+        assert!(fired.iter().all(|event| event.id == id && event.label.as_deref() == Some("watch")));
+        assert_eq!(fired[0].elapsed_seconds, 6.0 * 3600.0);
+        assert_eq!(fired[3].elapsed_seconds, 86400.0);
+
+        InGameClock::remove_schedule(app.world_mut(), id);
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = 2.0 * 86400.0;
+        app.update();
+        assert!(app.world_mut().resource_mut::<Messages<ScheduleFired>>().drain().next().is_none());
+    }
+
+    #[test]
+    fn test_schedule_resumes_search() {
+        // The first leap day is in year 20, about 20000 days after the epoch
+        let calendar = CustomCalendar::builder()
+            .month(Month::new("Frostmoon", 1000, 1))
+            .weekday("Moonday")
+            .leap_years("# % 20 == 0")
+            .build();
+        let seconds_per_day = calendar.seconds_per_day() as f64;
+        let leap_day = calendar.to_elapsed((20, 1, 1001), (0, 0, 0), start()).unwrap();
+        let schedule = ClockSchedule::parse("0 0 1001 * *", &calendar).unwrap();
+        assert_eq!(schedule.next_occurrence(0.0, &calendar, start()), None);
+
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin)
+            .init_resource::<Time>()
+            .insert_resource(InGameClock::new().with_calendar(calendar));
+        let id = InGameClock::add_schedule(app.world_mut(), schedule);
+        app.update();
+
+        // The search stopped after 10000 days and is not repeated before the clock gets there
+        let next = |app: &App| app.world().resource::<ClockSchedules>().schedules[0].next;
+        assert_eq!(next(&app), Some(Upcoming::NotBefore(10_000.0 * seconds_per_day)));
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = 5000.0 * seconds_per_day;
+        app.update();
+        assert_eq!(next(&app), Some(Upcoming::NotBefore(10_000.0 * seconds_per_day)));
+
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = leap_day + seconds_per_day;
+        app.update();
+        let fired: Vec<ScheduleFired> = app.world_mut().resource_mut::<Messages<ScheduleFired>>().drain().collect();
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].id, id);
+        assert_eq!(fired[0].elapsed_seconds, leap_day);
+    }
+}
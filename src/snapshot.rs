@@ -0,0 +1,295 @@
+//! Serializable snapshots of the clock state for save games.
+//!
+//! [`InGameClock`] holds its calendar as an opaque `Arc<dyn Calendar>` and the interval
+//! trackers, alarms and schedules live in private resources, so none of them can be
+//! serialized directly. A [`ClockSnapshot`] captures all of them, and can be restored to rebuild
+//! them exactly.
+
+use crate::alarm::{ClockAlarms, NEXT_ALARM_ID};
+use crate::schedule::{ClockSchedules, ScheduleEntry, NEXT_SCHEDULE_ID};
+use crate::{
+    AlarmId, ClockAlarm, ClockSchedule, ScheduleId,
+    CalendarDefinition, ClockInterval, ClockIntervalTrackers, InGameClock, IntervalHandle, IntervalSettings,
+    IntervalTracker, NEXT_INTERVAL_HANDLE,
+};
+use bevy::prelude::*;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+
+/// Serializable state of the in-game clock and all registered intervals
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ingame_clock::{ClockSnapshot, InGameClock, InGameClockPlugin};
+/// let mut app = App::new();
+/// app.add_plugins(InGameClockPlugin)
+///     .insert_resource(InGameClock::with_start_datetime(2024, 6, 15, 8, 0, 0));
+///
+/// // Save: capture the clock and store the snapshot with your save data
+/// let snapshot = ClockSnapshot::capture(app.world()).unwrap();
+///
+/// // Load: rebuild the clock and its interval trackers
+/// snapshot.restore(app.world_mut());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClockSnapshot {
+    /// The elapsed in-game time in seconds since the start_datetime
+    pub elapsed_seconds: f64,
+    /// The speed multiplier for the clock
+    pub speed: f32,
+    /// Whether the clock was paused
+    pub paused: bool,
+    /// The start date/time of the clock
+    pub start_datetime: NaiveDateTime,
+    /// Seconds from the origin of the calendar to the start of the clock
+    #[serde(default)]
+    pub start_offset: f64,
+    /// The calendar system used by the clock
+    pub calendar: CalendarDefinition,
+    /// The state of every registered interval tracker
+    pub intervals: Vec<IntervalTrackerSnapshot>,
+    /// Every alarm that has not fired yet
+    #[serde(default)]
+    pub alarms: Vec<(AlarmId, ClockAlarm)>,
+    /// Every registered recurring schedule
+    #[serde(default)]
+    pub schedules: Vec<(ScheduleId, ClockSchedule)>,
+}
+
+/// Serializable state of a single registered interval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntervalTrackerSnapshot {
+    /// The tracked interval
+    pub interval: ClockInterval,
+    /// The settings the interval was registered with
+    #[serde(default)]
+    pub settings: IntervalSettings,
+    /// The elapsed seconds at which the tracker was last checked
+    pub last_trigger_seconds: f64,
+    /// The number of times this interval has fired
+    pub count: u64,
+    /// The handles owning this interval, so they stay valid after a restore
+    #[serde(default)]
+    pub owners: Vec<IntervalHandle>,
+}
+
+impl ClockSnapshot {
+    /// Capture the current clock and interval state from the world
+    ///
+    /// Returns `None` if there is no [`InGameClock`] resource, or if its calendar
+    /// does not provide a [`CalendarDefinition`](crate::Calendar::definition).
+    pub fn capture(world: &World) -> Option<Self> {
+        let clock = world.get_resource::<InGameClock>()?;
+        let calendar = clock.calendar().definition()?;
+
+        let intervals = world
+            .get_resource::<ClockIntervalTrackers>()
+            .map(|trackers| {
+                trackers
+                    .trackers
+                    .iter()
+                    .map(|tracker| IntervalTrackerSnapshot {
+                        interval: tracker.interval,
+                        settings: tracker.settings,
+                        last_trigger_seconds: tracker.last_trigger_seconds,
+                        count: tracker.count,
+                        owners: tracker.owners.clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let alarms = world
+            .get_resource::<ClockAlarms>()
+            .map(|alarms| alarms.alarms.clone())
+            .unwrap_or_default();
+
+        let schedules = world
+            .get_resource::<ClockSchedules>()
+            .map(|schedules| {
+                schedules
+                    .schedules
+                    .iter()
+                    .map(|entry| (entry.id, entry.schedule.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Self {
+            elapsed_seconds: clock.elapsed_seconds,
+            speed: clock.speed,
+            paused: clock.paused,
+            start_datetime: clock.start_datetime,
+            start_offset: clock.start_offset(),
+            calendar,
+            intervals,
+            alarms,
+            schedules,
+        })
+    }
+
+    /// Rebuild the [`InGameClock`] described by this snapshot, without interval state
+    pub fn to_clock(&self) -> InGameClock {
+        let mut clock = InGameClock {
+            elapsed_seconds: self.elapsed_seconds,
+            speed: self.speed,
+            paused: self.paused,
+            start_datetime: self.start_datetime,
+            ..InGameClock::default()
+        };
+        clock.set_calendar(self.calendar.clone().into_calendar(), self.start_offset);
+        clock
+    }
+
+    /// Restore the clock, all interval trackers, pending alarms and schedules into the world
+    ///
+    /// Replaces the [`InGameClock`] resource, every registered interval, alarm and schedule,
+    /// so interval counts continue from the saved values instead of restarting at zero.
+    pub fn restore(self, world: &mut World) {
+        // Make sure handles created after the restore never collide with restored ones
+        if let Some(max_id) = self.intervals.iter().flat_map(|i| &i.owners).map(|h| h.id).max() {
+            NEXT_INTERVAL_HANDLE.fetch_max(max_id + 1, Ordering::Relaxed);
+        }
+        if let Some(max_id) = self.alarms.iter().map(|(id, _)| id.0).max() {
+            NEXT_ALARM_ID.fetch_max(max_id + 1, Ordering::Relaxed);
+        }
+        if let Some(max_id) = self.schedules.iter().map(|(id, _)| id.0).max() {
+            NEXT_SCHEDULE_ID.fetch_max(max_id + 1, Ordering::Relaxed);
+        }
+
+        world.insert_resource(self.to_clock());
+        world.insert_resource(ClockIntervalTrackers {
+            trackers: self
+                .intervals
+                .into_iter()
+                .map(|interval| IntervalTracker {
+                    interval: interval.interval,
+                    settings: interval.settings,
+                    last_trigger_seconds: interval.last_trigger_seconds,
+                    count: interval.count,
+                    owners: interval.owners,
+                })
+                .collect(),
+        });
+        world.insert_resource(ClockAlarms { alarms: self.alarms });
+        world.insert_resource(ClockSchedules {
+            schedules: self
+                .schedules
+                .into_iter()
+                .map(|(id, schedule)| ScheduleEntry::new(id, schedule))
+                .collect(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomCalendar, Epoch, InGameClockPlugin, Month};
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut world = World::new();
+        world.insert_resource(InGameClock::with_start_datetime(2024, 3, 1, 6, 0, 0).with_speed(4.0));
+        world.init_resource::<ClockIntervalTrackers>();
+        let handle = InGameClock::register_interval(&mut world, ClockInterval::Hour);
+        {
+            let mut trackers = world.resource_mut::<ClockIntervalTrackers>();
+            trackers.trackers[0].last_trigger_seconds = 7300.0;
+            trackers.trackers[0].count = 2;
+        }
+        world.resource_mut::<InGameClock>().elapsed_seconds = 7300.0;
+        world.init_resource::<ClockAlarms>();
+        InGameClock::schedule_alarm(&mut world, ClockAlarm::at_elapsed(9000.0).with_label("noon")).unwrap();
+
+        let snapshot = ClockSnapshot::capture(&world).unwrap();
+        let serialized = ron::to_string(&snapshot).unwrap();
+        let deserialized: ClockSnapshot = ron::from_str(&serialized).unwrap();
+
+        let mut restored = World::new();
+        deserialized.restore(&mut restored);
+
+        let clock = restored.resource::<InGameClock>();
+        assert_eq!(clock.elapsed_seconds, 7300.0);
+        assert_eq!(clock.speed, 4.0);
+        assert_eq!(clock.format_datetime(None), "2024-03-01 08:01:40");
+
+        let trackers = restored.resource::<ClockIntervalTrackers>();
+        assert_eq!(trackers.trackers.len(), 1);
+        assert_eq!(trackers.trackers[0].interval, ClockInterval::Hour);
+        assert_eq!(trackers.trackers[0].count, 2);
+
+        // Pending alarms survive the round trip
+        assert_eq!(restored.resource::<ClockAlarms>().alarms.len(), 1);
+
+        // Handles from before the save still own the restored tracker
+        InGameClock::unregister_interval(&mut restored, handle);
+        assert!(restored.resource::<ClockIntervalTrackers>().trackers.is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_custom_calendar() {
+        let calendar = CustomCalendar::builder()
+            .hours_per_day(20)
+            .month(Month::new("Frostmoon", 20, 3))
+            .weekday("Moonday")
+            .epoch(Epoch::new("Age of Magic", 1000))
+            .build();
+        let mut clock = InGameClock::new()
+            .with_calendar(calendar)
+            .with_calendar_start(1005, 1, 2, 0, 0, 0)
+            .unwrap();
+        clock.elapsed_seconds = 72000.0 * 3.0;
+
+        let mut world = World::new();
+        world.insert_resource(clock);
+
+        let snapshot = ClockSnapshot::capture(&world).unwrap();
+        assert!(snapshot.intervals.is_empty());
+        let serialized = ron::to_string(&snapshot).unwrap();
+        let deserialized: ClockSnapshot = ron::from_str(&serialized).unwrap();
+
+        let clock = deserialized.to_clock();
+        assert_eq!(clock.calendar().seconds_per_day(), 72000);
+        assert_eq!(clock.current_date(), (1005, 1, 5));
+    }
+
+    #[test]
+    fn test_restored_intervals_keep_counting() {
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin).init_resource::<Time>();
+        ClockSnapshot {
+            elapsed_seconds: 3.5 * 3600.0,
+            speed: 1.0,
+            paused: false,
+            start_datetime: InGameClock::with_start_datetime(2024, 1, 1, 0, 0, 0).start_datetime,
+            start_offset: 0.0,
+            calendar: CalendarDefinition::Gregorian,
+            intervals: vec![IntervalTrackerSnapshot {
+                interval: ClockInterval::Hour,
+                settings: IntervalSettings::default(),
+                last_trigger_seconds: 3.5 * 3600.0,
+                count: 3,
+                owners: Vec::new(),
+            }],
+            alarms: Vec::new(),
+            schedules: Vec::new(),
+        }
+        .restore(app.world_mut());
+
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds = 4.5 * 3600.0;
+        app.update();
+
+        let trackers = app.world().resource::<ClockIntervalTrackers>();
+        assert_eq!(trackers.trackers[0].count, 4);
+
+        // Releasing an unrelated handle keeps the restored tracker without owners
+        let handle = InGameClock::register_interval(app.world_mut(), ClockInterval::Day);
+        InGameClock::unregister_interval(app.world_mut(), handle);
+        let trackers = app.world().resource::<ClockIntervalTrackers>();
+        assert_eq!(trackers.trackers.len(), 1);
+        assert_eq!(trackers.trackers[0].interval, ClockInterval::Hour);
+    }
+}
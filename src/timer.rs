@@ -0,0 +1,277 @@
+//! Entity-scoped timers that tick with in-game time.
+
+use crate::{Calendar, InGameClock};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A unit of in-game time, resolved against the clock's calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TimeUnit {
+    /// One in-game second
+    Second,
+    /// One minute of the calendar
+    Minute,
+    /// One hour of the calendar
+    Hour,
+    /// One day of the calendar
+    Day,
+    /// One week of the calendar
+    Week,
+}
+
+impl TimeUnit {
+    /// Get the length of this unit in seconds, based on the calendar
+    pub fn as_seconds(&self, calendar: &dyn Calendar) -> f64 {
+        match self {
+            TimeUnit::Second => 1.0,
+            TimeUnit::Minute => calendar.seconds_per_minute() as f64,
+            TimeUnit::Hour => calendar.seconds_per_hour() as f64,
+            TimeUnit::Day => calendar.seconds_per_day() as f64,
+            TimeUnit::Week => calendar.seconds_per_week() as f64,
+        }
+    }
+}
+
+/// Timer component that ticks with the in-game clock instead of real time
+///
+/// The timer follows [`InGameClock::speed`] and stops while the clock is paused. Its
+/// duration is given in calendar units, so "2 days" lasts two of the active calendar's
+/// days. When it finishes, a [`TimerFinished`] event is triggered for its entity.
+///
+/// # Examples
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ingame_clock::{InGameTimer, TimeUnit, TimerFinished};
+/// fn plant_crop(mut commands: Commands) {
+///     commands
+///         .spawn(InGameTimer::new(2.0, TimeUnit::Day, TimerMode::Once))
+///         .observe(|finished: On<TimerFinished>| {
+///             println!("Crop {:?} is ready to harvest", finished.entity);
+///         });
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct InGameTimer {
+    amount: f64,
+    unit: TimeUnit,
+    mode: TimerMode,
+    elapsed: f64,
+    paused: bool,
+    finished: bool,
+    times_finished_this_tick: u32,
+}
+
+impl InGameTimer {
+    /// Creates a timer lasting `amount` of the given calendar unit
+    pub fn new(amount: f64, unit: TimeUnit, mode: TimerMode) -> Self {
+        Self {
+            amount,
+            unit,
+            mode,
+            elapsed: 0.0,
+            paused: false,
+            finished: false,
+            times_finished_this_tick: 0,
+        }
+    }
+
+    /// Creates a timer lasting the given number of in-game seconds
+    pub fn from_seconds(seconds: f64, mode: TimerMode) -> Self {
+        Self::new(seconds, TimeUnit::Second, mode)
+    }
+
+    /// Get the duration of the timer in in-game seconds, based on the calendar
+    pub fn duration_seconds(&self, calendar: &dyn Calendar) -> f64 {
+        self.amount * self.unit.as_seconds(calendar)
+    }
+
+    /// Get the in-game seconds elapsed since the timer started or last repeated
+    pub fn elapsed_seconds(&self) -> f64 {
+        self.elapsed
+    }
+
+    /// Get the in-game seconds left until the timer finishes
+    pub fn remaining_seconds(&self, calendar: &dyn Calendar) -> f64 {
+        (self.duration_seconds(calendar) - self.elapsed).max(0.0)
+    }
+
+    /// Get the fraction of the duration that has elapsed, from 0.0 to 1.0
+    pub fn fraction(&self, calendar: &dyn Calendar) -> f64 {
+        let duration = self.duration_seconds(calendar);
+        if duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / duration).min(1.0)
+        }
+    }
+
+    /// Get the mode of the timer
+    pub fn mode(&self) -> TimerMode {
+        self.mode
+    }
+
+    /// Returns `true` if a `Once` timer has finished
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns `true` if the timer finished during the last tick
+    pub fn just_finished(&self) -> bool {
+        self.times_finished_this_tick > 0
+    }
+
+    /// Get how many times the timer finished during the last tick
+    pub fn times_finished_this_tick(&self) -> u32 {
+        self.times_finished_this_tick
+    }
+
+    /// Pauses this timer, without affecting the clock or other timers
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes this timer
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns `true` if this timer is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Restarts the timer from zero
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+        self.finished = false;
+        self.times_finished_this_tick = 0;
+    }
+
+    /// Advances the timer by `delta` in-game seconds
+    pub fn tick(&mut self, delta: f64, calendar: &dyn Calendar) -> &Self {
+        self.times_finished_this_tick = 0;
+        if self.paused || self.finished {
+            return self;
+        }
+
+        let duration = self.duration_seconds(calendar);
+        self.elapsed += delta;
+        if self.elapsed < duration {
+            return self;
+        }
+
+        match self.mode {
+            TimerMode::Once => {
+                self.elapsed = duration;
+                self.finished = true;
+                self.times_finished_this_tick = 1;
+            }
+            TimerMode::Repeating if duration <= 0.0 => {
+                self.elapsed = 0.0;
+                self.times_finished_this_tick = 1;
+            }
+            TimerMode::Repeating => {
+                self.times_finished_this_tick = (self.elapsed / duration).floor() as u32;
+                self.elapsed %= duration;
+            }
+        }
+        self
+    }
+}
+
+/// Event triggered for an entity when its [`InGameTimer`] finishes
+#[derive(EntityEvent, Debug, Clone)]
+pub struct TimerFinished {
+    /// The entity owning the timer
+    pub entity: Entity,
+    /// How many times the timer finished during this tick (more than one for
+    /// short repeating timers at high clock speeds)
+    pub times: u32,
+}
+
+/// System that advances all timers by the in-game time passed since the last frame
+pub(crate) fn tick_timers(
+    clock: Res<InGameClock>,
+    mut last_elapsed: Local<Option<f64>>,
+    mut timers: Query<(Entity, &mut InGameTimer)>,
+    mut commands: Commands,
+) {
+    // Time moving backwards (e.g. a reset of `elapsed_seconds`) does not rewind timers
+    let delta = last_elapsed.map_or(0.0, |last| (clock.elapsed_seconds - last).max(0.0));
+    *last_elapsed = Some(clock.elapsed_seconds);
+
+    let calendar = clock.calendar().as_ref();
+    for (entity, mut timer) in &mut timers {
+        let times = timer.tick(delta, calendar).times_finished_this_tick();
+        if times > 0 {
+            commands.trigger(TimerFinished { entity, times });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomCalendar, GregorianCalendar, InGameClockPlugin, Month};
+
+    #[test]
+    fn test_timer_uses_calendar_units() {
+        let calendar = CustomCalendar::builder()
+            .minutes_per_hour(20)
+            .hours_per_day(20)
+            .month(Month::new("Month1", 30, 0))
+            .weekday("Day1")
+            .build();
+        let mut timer = InGameTimer::new(2.0, TimeUnit::Day, TimerMode::Once);
+        assert_eq!(timer.duration_seconds(&calendar), 48000.0);
+        assert_eq!(timer.duration_seconds(&GregorianCalendar), 172800.0);
+
+        assert!(!timer.tick(24000.0, &calendar).just_finished());
+        assert_eq!(timer.fraction(&calendar), 0.5);
+        assert!(timer.tick(30000.0, &calendar).just_finished());
+        assert!(timer.is_finished());
+        assert!(!timer.tick(30000.0, &calendar).just_finished());
+    }
+
+    #[test]
+    fn test_repeating_and_paused_timer() {
+        let calendar = GregorianCalendar;
+        let mut timer = InGameTimer::from_seconds(10.0, TimerMode::Repeating);
+        assert_eq!(timer.tick(35.0, &calendar).times_finished_this_tick(), 3);
+        assert_eq!(timer.elapsed_seconds(), 5.0);
+
+        timer.pause();
+        assert!(!timer.tick(100.0, &calendar).just_finished());
+        assert_eq!(timer.elapsed_seconds(), 5.0);
+        timer.unpause();
+        assert!(timer.tick(5.0, &calendar).just_finished());
+    }
+
+    #[derive(Resource, Default)]
+    struct Finished(Vec<(Entity, u32)>);
+
+    #[test]
+    fn test_timers_follow_clock() {
+        let mut app = App::new();
+        app.add_plugins(InGameClockPlugin)
+            .init_resource::<Time>()
+            .init_resource::<Finished>()
+            .add_observer(|finished: On<TimerFinished>, mut log: ResMut<Finished>| {
+                log.0.push((finished.entity, finished.times));
+            });
+        let cooldown = app.world_mut().spawn(InGameTimer::new(1.0, TimeUnit::Hour, TimerMode::Once)).id();
+        app.update();
+
+        // Paused clocks do not advance timers
+        app.world_mut().resource_mut::<InGameClock>().pause();
+        app.update();
+        app.world_mut().resource_mut::<InGameClock>().resume();
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds += 3599.0;
+        app.update();
+        assert!(app.world().resource::<Finished>().0.is_empty());
+
+        app.world_mut().resource_mut::<InGameClock>().elapsed_seconds += 1.0;
+        app.update();
+        assert_eq!(app.world().resource::<Finished>().0, vec![(cooldown, 1)]);
+    }
+}